@@ -4,15 +4,19 @@ use std::io::Write;
 use std::path::Path;
 
 fn main() {
-    let slas_env_vars = [("BLAS_IN_DOT_IF_LEN_GE", "750")];
+    let slas_env_vars = [
+        ("BLAS_IN_DOT_IF_LEN_GE", "750"),
+        ("BLAS_IN_GEMM_IF_SIZE_GE", "512"),
+        ("SIMD_IN_ADD_IF_LEN_GE", "8"),
+    ];
 
     let out_dir = env::var("OUT_DIR").unwrap();
     let dest_path = Path::new(&out_dir).join("config.rs");
     let mut f = File::create(&dest_path).unwrap();
 
     for (var, default_value) in slas_env_vars {
-        println!("cargo:rerun-if-env-changed={var}");
-        let value = env::var(&format!("SLAS_{var}")).unwrap_or(default_value.to_string());
+        println!("cargo:rerun-if-env-changed=SLAS_{var}");
+        let value = env::var(&format!("SLAS_{var}")).unwrap_or_else(|_| default_value.to_string());
         f.write_all(
             format!(
                 "
@@ -25,4 +29,33 @@ fn main() {
         )
         .unwrap();
     }
+
+    println!("cargo:rerun-if-env-changed=SLAS_DEFAULT_BACKEND");
+    let blas_enabled = env::var("CARGO_FEATURE_BLAS").is_ok();
+    let backend = match env::var("SLAS_DEFAULT_BACKEND") {
+        Ok(b) if b.eq_ignore_ascii_case("blas") => {
+            if !blas_enabled {
+                panic!("SLAS_DEFAULT_BACKEND is set to Blas, but the blas feature is not enabled")
+            }
+            "Blas"
+        }
+        Ok(b) if b.eq_ignore_ascii_case("rust") => "Rust",
+        Ok(b) => panic!(
+            "SLAS_DEFAULT_BACKEND must be either Blas or Rust, found {:?}",
+            b
+        ),
+        Err(_) if blas_enabled => "Blas",
+        Err(_) => "Rust",
+    };
+    f.write_all(
+        format!(
+            "
+            /// Backend selected by the environment variable `SLAS_DEFAULT_BACKEND` during build (`Blas` or `Rust`).
+            /// Defaults to `Blas` if the `blas` feature is enabled, otherwise `Rust`.
+            pub type DefaultBackend = crate::backends::{backend};
+         "
+        )
+        .as_bytes(),
+    )
+    .unwrap();
 }
@@ -0,0 +1,53 @@
+//! Procedural macros for [slas](https://lib.rs/slas).
+//! These are re-exported by slas, and should not be used directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote, Ident, ItemFn, LitInt, Token,
+};
+
+/// Arguments of the profile attribute, fx. `#[slas::profile(runs = 10)]`.
+struct ProfileArgs {
+    runs: u64,
+}
+
+impl Parse for ProfileArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut runs = 100;
+        if !input.is_empty() {
+            let name: Ident = input.parse()?;
+            if name != "runs" {
+                return Err(syn::Error::new(name.span(), "expected `runs = <number>`"));
+            }
+            input.parse::<Token![=]>()?;
+            let lit: LitInt = input.parse()?;
+            runs = lit.base10_parse()?;
+            if runs == 0 {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    "`runs` must be greater than zero",
+                ));
+            }
+        }
+        Ok(Self { runs })
+    }
+}
+
+/// See `slas::profile`.
+#[proc_macro_attribute]
+pub fn profile(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let ProfileArgs { runs } = parse_macro_input!(attr as ProfileArgs);
+    let mut f = parse_macro_input!(item as ItemFn);
+
+    let name = f.sig.ident.to_string();
+    let body = &f.block;
+    f.block = parse_quote!({
+        let _slas_profile_guard =
+            ::slas::profile::Guard::enter(concat!(module_path!(), "::", #name), #runs);
+        #body
+    });
+
+    quote!(#f).into()
+}
@@ -10,11 +10,25 @@
 //! ### operations::DotProduct
 //! Implemented for complex and real floats on [`slas_backend::Blas`].
 //!
-//! Implemented for real floats on [`slas_backend::Rust`].
+//! Implemented for real floats and [`crate::dual::Dual`] numbers on [`slas_backend::Rust`].
 //!
 //! #### dot
 //! Should take two vectors of equal length, and return their dot product.
 //!
+//! ### operations::InnerProduct
+//! Implemented for complex floats on [`slas_backend::Blas`] and [`slas_backend::Rust`].
+//!
+//! #### inner_product
+//! Should return the Hermitian inner product `sum(conj(a) * b)` of two vectors of equal length.
+//! Unlike [`operations::DotProduct`] for complex numbers, the elements of `a` are conjugated.
+//!
+//! ### operations::WeightedDot
+//! Implemented for f32 and f64 -floats on [`slas_backend::Rust`] and [`slas_backend::Blas`].
+//! See also [`weighted_dot`].
+//!
+//! #### weighted_dot
+//! Should return `sum(w * a * b)` for three vectors of equal length.
+//!
 //! ### operations::Normalize
 //! Implemented for real floats on [`slas_backend::Rust`].
 //!
@@ -24,14 +38,78 @@
 //! #### normalize
 //! Should normalize self (devide each element by the norm of the vector)
 //!
+//! ### operations::LpNorm
+//! Implemented for f32 and f64 -floats on [`slas_backend::Rust`].
+//!
+//! #### l1_norm
+//! Should return the sum of the absolute values of a vector.
+//!
+//! #### linf_norm
+//! Should return the largest absolute value of a vector.
+//!
+//! #### p_norm
+//! Should return `(sum(|x|^p))^(1/p)`.
+//!
+//! ### operations::Axpy
+//! Implemented for f32 and f64 -floats on [`slas_backend::Rust`] and [`slas_backend::Blas`].
+//!
+//! #### axpy
+//! Should add `alpha * x` to `y` in-place (`y = alpha * x + y`).
+//!
+//! ### operations::ScaleInPlace
+//! Implemented for f32 and f64 -floats on [`slas_backend::Rust`] and [`slas_backend::Blas`].
+//!
+//! #### scal
+//! Should multiply each element of `x` by `alpha` in-place.
+//!
+//! ### operations::AbsSum
+//! Implemented for f32 and f64 -floats on [`slas_backend::Rust`] and [`slas_backend::Blas`].
+//!
+//! #### asum
+//! Should return the sum of the absolute values of a vector.
+//!
+//! ### operations::AbsArgmax
+//! Implemented for f32 and f64 -floats on [`slas_backend::Rust`] and [`slas_backend::Blas`].
+//!
+//! #### iamax
+//! Should return the index of the first element with the largest absolute value.
+//!
 //! ### operations::MatrixMul
-//! Implemented for f32 and f64 -floats on [`slas_backend::Blas`].
+//! Implemented for f32 and f64 -floats on [`slas_backend::Blas`] and [`slas_backend::Rust`].
+//! The blas backend uses the rust backend for matricies with `m * n * k < slas::config::BLAS_IN_GEMM_IF_SIZE_GE`.
 //!
 //! #### matrix_mul
 //! Matrix-Matrix multiplication
 //!
-//! #### vector_mul
-//! Matrix-Vector multiplication
+//! ### operations::MatrixVectorMul
+//! Implemented for all floats on [`slas_backend::Rust`], and for f32 and f64 -floats on [`slas_backend::Blas`] (using gemv).
+//!
+//! #### matrix_vector_mul
+//! Matrix-Vector multiplication `y = op(a) * x`, where `op(a)` is a `m`x`n` matrix.
+//! `a` is stored row-major with leading dimension `lda`, and is transposed if `trans` is true.
+//!
+//! ### operations::SymmetricMatrixVectorMul
+//! Implemented for f32 and f64 -floats on [`slas_backend::Blas`].
+//! See also [`slas_backend::Blas::symmetric_vector_mul`].
+//!
+//! #### symv
+//! Write `a * x` into `y`, where `a` is a symmetric row-major `N`x`N` matrix.
+//! Only the upper triangle of `a` is read if `upper` is true, otherwise only the lower triangle is read.
+//!
+//! ### operations::TriangularSolve
+//! Implemented for all floats on [`slas_backend::Rust`], and for f32 and f64 -floats on [`slas_backend::Blas`].
+//!
+//! #### triangular_solve
+//! Solve `a * x = b` in place, where `a` is a square row-major triangular matrix and `x` initially contains `b`.
+//! If `upper` is true `a` is upper triangular, otherwise it is lower triangular.
+//! If `unit_diagonal` is true the diagonal of `a` is assumed to be all ones.
+//!
+//! ### operations::Inverse
+//! Implemented for all floats on [`slas_backend::Rust`], and for f32 and f64 -floats on [`slas_backend::Blas`] when the `lapack` feature is enabled.
+//!
+//! #### inverse
+//! Write the inverse of the square row-major matrix `a` into buffer.
+//! Should return false if `a` is singular, meaning that a pivot smaller than `epsilon` was found during LU decomposition.
 //!
 //! ### operations::Transpose
 //! Operation for transposing **matricies**, not general tensors.
@@ -50,6 +128,37 @@
 //! which takes two input vectors and a buffer,
 //! same applies to other element-wise operations.
 //!
+//! ### ElementwiseMax and ElementwiseMin
+//! Implemented for f32, f64, i32 and i64 on [`slas_backend::Rust`].
+//! See also [`crate::tensor::Matrix::elementwise_max`].
+//!
+//! The methods `elementwise_max` and `elementwise_min` write the larger or smaller of each pair of elements in `a` and `b` into `c`.
+//!
+//! ### Wrapping and saturating integer arithmetic
+//! Element-wise addition, subtraction and multiplication for integer vectors,
+//! implemented on [`slas_backend::Rust`] for i32 and i64.
+//!
+//! The operations are `WrappingAddition`, `WrappingSubtraction`, `WrappingMultiplication`,
+//! `SaturatingAddition`, `SaturatingSubtraction` and `SaturatingMultiplication`,
+//! with the methods `wrapping_add`, `saturating_add` and so on.
+//! They take the same arguments as the floating point element-wise operations.
+//!
+//! ### operations::VectorizedTranscendental
+//! Implemented for f32 and f64 -floats on [`slas_backend::Rust`].
+//! With the `sleef` feature and avx2 enabled, the [SLEEF](https://sleef.org) simd functions are used.
+//!
+//! #### vsin, vcos, vexp and vln
+//! Write the element-wise sine, cosine, exponential or natural logarithm of `a` into buffer.
+//!
+//! ### Logging backend
+//! [`slas_backend::BackendWrapper`] implements every operation that its inner backend implements,
+//! and reports the name and duration of each call to a [`slas_backend::BackendLogger`].
+//!
+//! ### Parallel backend
+//! With the `parallel` feature, [`slas_backend::Rayon`] implements `DotProduct`, `Normalize`,
+//! `Addition` and `Multiplication` for f32 and f64,
+//! by splitting vectors into chunks that are processed on multiple threads.
+//!
 //! ## How to specify backend
 //!
 //! If you're trying to use slas on a system where blas isn't available,
@@ -95,7 +204,28 @@ macro_rules! impl_operations {
 	($_t:ident $($name: ident $($op: ident ($($generics: tt)*) ($($generics_use: tt)*) ($($arg: ident : $arg_ty: ty),*)
         where ($($where_ty:ty : $implements: path),*)  -> $t: ty),*);*;) => {
 
+        /// Operations that can be implemented for a backend. See [`Backend::supports`].
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum BackendOp {
+            $($name),*
+        }
+
         pub trait Backend<$_t>: Default{
+            /// Returns true if the backend implements `op` for the element type.
+            /// This can be used to select a backend at runtime.
+            ///
+            /// ## Example
+            /// ```rust
+            /// use slas::prelude::*;
+            /// use slas_backend::{BackendOp, Blas, Rust};
+            ///
+            /// assert!(Backend::<f32>::supports(&Blas, BackendOp::MatrixMul));
+            /// assert!(!Backend::<f32>::supports(&Rust, BackendOp::SymmetricMatrixVectorMul));
+            /// ```
+            fn supports(&self, _op: BackendOp) -> bool {
+                false
+            }
+
             $($(
                 fn $op<$($generics)*>(&self, $($arg : $arg_ty),*) -> paste!( <Self as operations::$name<$_t>>::[<$op:camel Output>] )
                 where
@@ -114,6 +244,17 @@ macro_rules! impl_operations {
                     fn $op<$($generics)*>(&self, $($arg : $arg_ty),*) -> paste!(Self::[<$op:camel Output>]) where $($where_ty : $implements),*;
                 )*
             })*
+
+            // The generic parameters are not named B and L, as they would shadow the generics of some operations.
+            $(impl<$_t, Inner: $name<$_t>, Logger: BackendLogger> $name<$_t> for BackendWrapper<Inner, Logger> {
+                $(
+                    paste!( type [<$op:camel Output>] = <Inner as $name<$_t>>::[<$op:camel Output>]; );
+                    #[inline(always)]
+                    fn $op<$($generics)*>(&self, $($arg : $arg_ty),*) -> paste!(Self::[<$op:camel Output>]) where $($where_ty : $implements),* {
+                        self.run(stringify!($op), || <Inner as $name<$_t>>::$op::<$($generics_use)*>(&self.inner, $($arg),*))
+                    }
+                )*
+            })*
         }
 	};
 }
@@ -125,10 +266,39 @@ impl_operations!(T
             b: &impl StaticVec<T, LEN>
         ) where () -> T;
 
+    InnerProduct
+        inner_product(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>
+        ) where () -> T;
+
+    WeightedDot
+        weighted_dot(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            w: &impl StaticVec<T, LEN>
+        ) where () -> T;
+
     Normalize
         norm(const LEN: usize)()(a: &impl StaticVec<T, LEN>) where () -> <Self as operations::Normalize<T>>::NormOutput,
         normalize(const LEN: usize)()(a: &mut impl StaticVec<T, LEN>) where (T: From<<Self as operations::Normalize<T>>::NormOutput>) -> ();
 
+    Axpy
+        axpy(const LEN: usize)()(
+            alpha: T,
+            x: &impl StaticVec<T, LEN>,
+            y: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    ScaleInPlace
+        scal(const LEN: usize)()(alpha: T, x: &mut impl StaticVec<T, LEN>) where () -> ();
+
+    AbsSum
+        asum(const LEN: usize)()(a: &impl StaticVec<T, LEN>) where () -> T;
+
+    AbsArgmax
+        iamax(const LEN: usize)()(a: &impl StaticVec<T, LEN>) where () -> usize;
+
     MatrixMul
         matrix_mul(A: StaticVec<T, ALEN>, B: StaticVec<T, BLEN>, C: StaticVec<T, CLEN>, const ALEN: usize, const BLEN: usize, const CLEN: usize)
         (A, B, C, ALEN, BLEN, CLEN)
@@ -138,17 +308,42 @@ impl_operations!(T
             B: Sized,
             C: Sized,
             T: Copy
-        ) -> (),
-        matrix_vector_mul(A: StaticVec<T, ALEN>, B: StaticVec<T, BLEN>, C: StaticVec<T, CLEN>, const ALEN: usize, const BLEN: usize, const CLEN: usize)
-        (A, B, C, ALEN, BLEN, CLEN)
-        (a: &A, b: &B, buffer: &mut C, m: usize, n: usize, lda: usize, a_trans: bool)
+        ) -> ();
+
+    MatrixVectorMul
+        matrix_vector_mul(A: StaticVec<T, ALEN>, X: StaticVec<T, XLEN>, Y: StaticVec<T, YLEN>, const ALEN: usize, const XLEN: usize, const YLEN: usize)
+        (A, X, Y, ALEN, XLEN, YLEN)
+        (a: &A, x: &X, y: &mut Y, m: usize, n: usize, lda: usize, trans: bool)
         where (
             A: Sized,
-            B: Sized,
-            C: Sized,
+            X: Sized,
+            Y: Sized,
             T: Copy
         ) -> ();
 
+    SymmetricMatrixVectorMul
+        symv(const N: usize, const LEN: usize)(N, LEN)(
+            a: &impl StaticVec<T, LEN>,
+            x: &impl StaticVec<T, N>,
+            y: &mut impl StaticVec<T, N>,
+            upper: bool
+        ) where () -> ();
+
+    TriangularSolve
+        triangular_solve(const LEN: usize, const N: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            x: &mut impl StaticVec<T, N>,
+            upper: bool,
+            unit_diagonal: bool
+        ) where () -> ();
+
+    Inverse
+        inverse(const N: usize, const LEN: usize)(N, LEN)(
+            a: &impl StaticVec<T, LEN>,
+            buffer: &mut impl StaticVec<T, LEN>,
+            epsilon: T
+        ) where () -> bool;
+
     Transpose
         transpose_inplace(const LEN: usize)()(a: &mut impl StaticVec<T, LEN>, columns: usize) where () -> (),
         transpose(const LEN: usize)()(a: &impl StaticVec<T, LEN>, buffer: &mut impl StaticVec<T, LEN>, columns: usize) where () -> ();
@@ -180,6 +375,73 @@ impl_operations!(T
             b: &impl StaticVec<T, LEN>,
             c: &mut impl StaticVec<T, LEN>
         ) where () -> ();
+
+    ElementwiseMax
+        elementwise_max(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            c: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    ElementwiseMin
+        elementwise_min(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            c: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    WrappingAddition
+        wrapping_add(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            c: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    WrappingSubtraction
+        wrapping_sub(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            c: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    WrappingMultiplication
+        wrapping_mul(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            c: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    SaturatingAddition
+        saturating_add(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            c: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    SaturatingSubtraction
+        saturating_sub(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            c: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    SaturatingMultiplication
+        saturating_mul(const LEN: usize)()(
+            a: &impl StaticVec<T, LEN>,
+            b: &impl StaticVec<T, LEN>,
+            c: &mut impl StaticVec<T, LEN>
+        ) where () -> ();
+
+    LpNorm
+        l1_norm(const LEN: usize)()(a: &impl StaticVec<T, LEN>) where () -> T,
+        linf_norm(const LEN: usize)()(a: &impl StaticVec<T, LEN>) where () -> T,
+        p_norm(const LEN: usize)()(a: &impl StaticVec<T, LEN>, p: T) where () -> T;
+
+    VectorizedTranscendental
+        vsin(const LEN: usize)()(a: &impl StaticVec<T, LEN>, buffer: &mut impl StaticVec<T, LEN>) where () -> (),
+        vcos(const LEN: usize)()(a: &impl StaticVec<T, LEN>, buffer: &mut impl StaticVec<T, LEN>) where () -> (),
+        vexp(const LEN: usize)()(a: &impl StaticVec<T, LEN>, buffer: &mut impl StaticVec<T, LEN>) where () -> (),
+        vln(const LEN: usize)()(a: &impl StaticVec<T, LEN>, buffer: &mut impl StaticVec<T, LEN>) where () -> ();
 );
 
 /// Perform opertaions on a [`StaticVec`] with a static backend.
@@ -251,11 +513,32 @@ impl<T, U: StaticVec<T, LEN>, B: Backend<T>, const LEN: usize> WithStaticBackend
 macro_rules! impl_default_ops {
     ($t: ty) => {
         impl<'a, const LEN: usize> StaticVecUnion<'a, Complex<$t>, LEN> {
-            /// Dot product for two complex vectors using blas.
+            /// Unconjugated dot product `sum(a * b)` for two complex vectors using blas.
             /// There is no rust backend for complex dot products at the moment.
-            pub fn dot(&self, other: &Self) -> Complex<$t> {
+            pub fn unconjugated_dot(&self, other: &Self) -> Complex<$t> {
                 Blas.dot(self, other)
             }
+
+            /// Unconjugated dot product for two complex vectors.
+            #[deprecated(
+                note = "complex `dot` does not conjugate the elements of self, use `unconjugated_dot` or `inner_product` instead"
+            )]
+            pub fn dot(&self, other: &Self) -> Complex<$t> {
+                self.unconjugated_dot(other)
+            }
+
+            /// Hermitian inner product `sum(conj(a) * b)` for two complex vectors using blas.
+            ///
+            /// ## Example
+            /// ```rust
+            /// use slas::prelude::*;
+            ///
+            /// let a = moo![Complex::<f32> { re: 1., im: 2. }; 3];
+            /// assert_eq!(a.inner_product(&a), Complex { re: 15., im: 0. });
+            /// ```
+            pub fn inner_product(&self, other: &Self) -> Complex<$t> {
+                Blas.inner_product(self, other)
+            }
         }
 
         impl<'a, const LEN: usize> StaticVecUnion<'a, $t, LEN> {
@@ -271,11 +554,25 @@ macro_rules! impl_default_ops {
             /// assert!(moo![f32: 0..4].dot([1.2; 4].moo_ref()) - 7.2 < 0.000003)
             /// ```
             pub fn dot(&self, other: &Self) -> $t {
-                if LEN >= crate::config::BLAS_IN_DOT_IF_LEN_GE {
-                    Blas.dot(self, other)
-                } else {
-                    Rust.dot(self, other)
+                let dot = |use_blas: bool| {
+                    if use_blas {
+                        Blas.dot(self, other)
+                    } else {
+                        Rust.dot(self, other)
+                    }
+                };
+                let use_blas = LEN >= crate::config::BLAS_IN_DOT_IF_LEN_GE;
+
+                #[cfg(feature = "profile")]
+                if crate::profile::is_active() {
+                    return crate::profile::compare(
+                        crate::profile::Knob::BlasInDot,
+                        LEN,
+                        use_blas,
+                        dot,
+                    );
                 }
+                dot(use_blas)
             }
         }
     };
@@ -314,6 +611,96 @@ impl<
         operations::DotProduct::<T>::dot(&self.backend, &self.data, &other.data)
     }
 }
+impl<
+        T,
+        U: StaticVec<T, LEN>,
+        B: Backend<T> + operations::WeightedDot<T, WeightedDotOutput = T>,
+        const LEN: usize,
+    > WithStaticBackend<T, U, B, LEN>
+{
+    /// Dot product of `self` and `other`, where each term is weighted by `w`.
+    /// See [`weighted_dot`].
+    pub fn weighted_dot<U2: StaticVec<T, LEN>, U3: StaticVec<T, LEN>>(
+        &self,
+        other: &WithStaticBackend<T, U2, B, LEN>,
+        w: &WithStaticBackend<T, U3, B, LEN>,
+    ) -> T {
+        operations::WeightedDot::<T>::weighted_dot(&self.backend, &self.data, &other.data, &w.data)
+    }
+}
+
+/// Weighted dot product `sum(w * a * b)`, using the rust backend.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// assert_eq!(weighted_dot(&[1f32, 2., 3.], &[4., 5., 6.], &[1., 0., 0.5]), 13.);
+/// ```
+pub fn weighted_dot<T, const LEN: usize>(
+    a: &impl StaticVec<T, LEN>,
+    b: &impl StaticVec<T, LEN>,
+    w: &impl StaticVec<T, LEN>,
+) -> T
+where
+    Rust: operations::WeightedDot<T, WeightedDotOutput = T>,
+{
+    operations::WeightedDot::<T>::weighted_dot(&Rust, a, b, w)
+}
+
+macro_rules! impl_static_backend_basic_op {
+    ($($op: ident $fn: ident),*) => {$(
+        impl<T: Float, U: StaticVec<T, LEN>, B: Backend<T> + operations::$op<T>, const LEN: usize>
+            WithStaticBackend<T, U, B, LEN>
+        {
+            /// Element-wise operation using the static backend, implemented automatically with macro.
+            pub fn $fn<U2: StaticVec<T, LEN>>(&self, other: &WithStaticBackend<T, U2, B, LEN>) -> [T; LEN] {
+                let mut buffer = [T::_0; LEN];
+                operations::$op::<T>::$fn(&self.backend, &self.data, &other.data, &mut buffer);
+                buffer
+            }
+        }
+    )*};
+}
+
+impl_static_backend_basic_op!(Addition add, Subtraction sub, Multiplication mul, Divition div);
+
+impl<T, U: StaticVec<T, LEN>, B: Backend<T> + operations::Axpy<T>, const LEN: usize>
+    WithStaticBackend<T, U, B, LEN>
+{
+    /// Add `alpha * x` to self in-place.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let mut params = moo![on slas_backend::Rust:f32: 1, 2, 3];
+    /// params.axpy(-0.5, &[2., 2., 2.]);
+    /// assert_eq!(***params, [0., 1., 2.]);
+    /// ```
+    pub fn axpy(&mut self, alpha: T, x: &impl StaticVec<T, LEN>) {
+        operations::Axpy::<T>::axpy(&self.backend, alpha, x, &mut self.data);
+    }
+}
+
+impl<T, U: StaticVec<T, LEN>, B: Backend<T> + operations::ScaleInPlace<T>, const LEN: usize>
+    WithStaticBackend<T, U, B, LEN>
+{
+    /// Multiply self by `alpha` in-place.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let mut v = moo![on slas_backend::Blas:f32: 1, 2, 3];
+    /// v.scal(2.);
+    /// assert_eq!(***v, [2., 4., 6.]);
+    /// ```
+    pub fn scal(&mut self, alpha: T) {
+        operations::ScaleInPlace::<T>::scal(&self.backend, alpha, &mut self.data);
+    }
+}
+
 impl<
         T: From<NormOutput>,
         NormOutput,
@@ -330,8 +717,37 @@ impl<
     }
 }
 
+/// Implement [`Backend`] for a backend and a list of element types,
+/// where `supports` returns true for the listed operations.
+macro_rules! impl_backend {
+    ($backend: ty: $($t: ty),* => $ops: tt) => {$(
+        impl Backend<$t> for $backend {
+            #[allow(clippy::match_like_matches_macro)]
+            fn supports(&self, op: BackendOp) -> bool {
+                impl_backend!(@supports op $ops)
+            }
+        }
+    )*};
+    (@supports $op: ident [$($(#[$cfg: meta])? $supported: ident),* $(,)?]) => {
+        match $op {
+            $($(#[$cfg])? BackendOp::$supported => true,)*
+            _ => false,
+        }
+    };
+}
+
 mod blas;
 pub use blas::Blas;
 
 mod rust;
 pub use rust::Rust;
+
+mod wrapper;
+pub use wrapper::{BackendLogger, BackendWrapper, StderrLogger};
+
+pub use crate::config::DefaultBackend;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::Rayon;
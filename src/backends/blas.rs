@@ -25,7 +25,7 @@ macro_rules! impl_dot {
 }
 
 macro_rules! impl_dot_comp {
-    ($t: ty, $comp_blas_fn: ident) => {
+    ($t: ty, $comp_blas_fn: ident, $conj_blas_fn: ident) => {
         /// Dot product for two complex vectors.
         /// Also has support for multiple (and mixed) types.
         impl operations::DotProduct<Complex<$t>> for Blas {
@@ -51,6 +51,90 @@ macro_rules! impl_dot_comp {
                 }
             }
         }
+
+        /// Hermitian inner product for two complex vectors.
+        impl operations::InnerProduct<Complex<$t>> for Blas {
+            fn inner_product<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<Complex<$t>, LEN>,
+                b: &impl StaticVec<Complex<$t>, LEN>,
+            ) -> Complex<$t> {
+                let mut tmp: [$t; 2] = [0.; 2];
+                unsafe {
+                    cblas_sys::$conj_blas_fn(
+                        LEN as i32,
+                        a.as_ptr() as *const [$t; 2],
+                        1,
+                        b.as_ptr() as *const [$t; 2],
+                        1,
+                        tmp.as_mut_ptr() as *mut [$t; 2],
+                    )
+                }
+                Complex {
+                    re: tmp[0],
+                    im: tmp[1],
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_weighted_dot {
+    ($t: ty, $blas_fn: ident) => {
+        /// The element-wise product of `a` and `w` is computed with [`Rust`], and then passed to blas as the first argument of a dot product.
+        impl operations::WeightedDot<$t> for Blas {
+            fn weighted_dot<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                b: &impl StaticVec<$t, LEN>,
+                w: &impl StaticVec<$t, LEN>,
+            ) -> $t {
+                let mut aw = [0.; LEN];
+                operations::Multiplication::mul(&Rust, a, w, &mut aw);
+                unsafe { cblas_sys::$blas_fn(LEN as i32, aw.as_ptr(), 1, b.as_ptr(), 1) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_axpy {
+    ($t: ty, $blas_fn: ident) => {
+        impl operations::Axpy<$t> for Blas {
+            fn axpy<const LEN: usize>(
+                &self,
+                alpha: $t,
+                x: &impl StaticVec<$t, LEN>,
+                y: &mut impl StaticVec<$t, LEN>,
+            ) {
+                unsafe { cblas_sys::$blas_fn(LEN as i32, alpha, x.as_ptr(), 1, y.as_mut_ptr(), 1) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_scal {
+    ($t: ty, $blas_fn: ident) => {
+        impl operations::ScaleInPlace<$t> for Blas {
+            fn scal<const LEN: usize>(&self, alpha: $t, x: &mut impl StaticVec<$t, LEN>) {
+                unsafe { cblas_sys::$blas_fn(LEN as i32, alpha, x.as_mut_ptr(), 1) }
+            }
+        }
+    };
+}
+
+macro_rules! impl_abs {
+    ($t: ty, $asum: ident, $iamax: ident) => {
+        impl operations::AbsSum<$t> for Blas {
+            fn asum<const LEN: usize>(&self, a: &impl StaticVec<$t, LEN>) -> $t {
+                unsafe { cblas_sys::$asum(LEN as i32, a.as_ptr(), 1) }
+            }
+        }
+
+        impl operations::AbsArgmax<$t> for Blas {
+            fn iamax<const LEN: usize>(&self, a: &impl StaticVec<$t, LEN>) -> usize {
+                unsafe { cblas_sys::$iamax(LEN as i32, a.as_ptr(), 1) as usize }
+            }
+        }
     };
 }
 
@@ -64,6 +148,9 @@ macro_rules! impl_gemm {
         /// for more information.
         ///
         /// It's notable that your left hand matrix needs to be as wide as the right hand matrix is tall.
+        ///
+        /// Matricies with `m * n * k < slas::config::BLAS_IN_GEMM_IF_SIZE_GE` are multiplied by the rust backend,
+        /// as the overhead of calling blas is larger than the work for small matricies.
         impl operations::MatrixMul<$t> for Blas {
             fn matrix_mul<
                 A: StaticVec<$t, ALEN>,
@@ -89,62 +176,86 @@ macro_rules! impl_gemm {
                 A: Sized,
                 B: Sized,
             {
-                use cblas_sys::CBLAS_TRANSPOSE::*;
-                unsafe {
-                    cblas_sys::$gemm(
-                        cblas_sys::CBLAS_LAYOUT::CblasRowMajor,
-                        if a_trans { CblasTrans } else { CblasNoTrans },
-                        if b_trans { CblasTrans } else { CblasNoTrans },
-                        m as i32,
-                        n as i32,
-                        k as i32,
-                        1.,                         // alpha
-                        a.as_ptr(),                 // a
-                        lda as i32,                 // lda
-                        b.as_ptr(),                 // b
-                        ldb as i32,                 // ldb
-                        0.,                         // beta
-                        buffer.as_ptr() as *mut $t, // c
-                        ldc as i32,                 // ldc
-                    )
+                let mut gemm = |use_blas: bool| {
+                    if !use_blas {
+                        return operations::MatrixMul::matrix_mul(
+                            &Rust, a, b, buffer, m, n, k, lda, ldb, ldc, a_trans, b_trans,
+                        );
+                    }
+
+                    use cblas_sys::CBLAS_TRANSPOSE::*;
+                    unsafe {
+                        cblas_sys::$gemm(
+                            cblas_sys::CBLAS_LAYOUT::CblasRowMajor,
+                            if a_trans { CblasTrans } else { CblasNoTrans },
+                            if b_trans { CblasTrans } else { CblasNoTrans },
+                            m as i32,
+                            n as i32,
+                            k as i32,
+                            1.,                         // alpha
+                            a.as_ptr(),                 // a
+                            lda as i32,                 // lda
+                            b.as_ptr(),                 // b
+                            ldb as i32,                 // ldb
+                            0.,                         // beta
+                            buffer.as_ptr() as *mut $t, // c
+                            ldc as i32,                 // ldc
+                        )
+                    }
+                };
+                let use_blas = m * n * k >= crate::config::BLAS_IN_GEMM_IF_SIZE_GE;
+
+                #[cfg(feature = "profile")]
+                if crate::profile::is_active() {
+                    return crate::profile::compare(
+                        crate::profile::Knob::BlasInGemm,
+                        m * n * k,
+                        use_blas,
+                        gemm,
+                    );
                 }
+                gemm(use_blas)
             }
+        }
 
+        impl operations::MatrixVectorMul<$t> for Blas {
             /// Matrix-vector multiplication using gemv.
             fn matrix_vector_mul<
                 A: StaticVec<$t, ALEN>,
-                B: StaticVec<$t, BLEN>,
-                C: StaticVec<$t, CLEN>,
+                X: StaticVec<$t, XLEN>,
+                Y: StaticVec<$t, YLEN>,
                 const ALEN: usize,
-                const BLEN: usize,
-                const CLEN: usize,
+                const XLEN: usize,
+                const YLEN: usize,
             >(
                 &self,
                 a: &A,
-                b: &B,
-                buffer: &mut C,
+                x: &X,
+                y: &mut Y,
                 m: usize,
                 n: usize,
                 lda: usize,
-                a_trans: bool,
+                trans: bool,
             ) where
                 A: Sized,
-                B: Sized,
+                X: Sized,
             {
                 use cblas_sys::CBLAS_TRANSPOSE::*;
+                // gemv takes the dimensions of a as it is stored, not of op(a).
+                let (rows, columns) = if trans { (n, m) } else { (m, n) };
                 unsafe {
                     cblas_sys::$gemv(
                         cblas_sys::CBLAS_LAYOUT::CblasRowMajor,
-                        if a_trans { CblasTrans } else { CblasNoTrans },
-                        n as i32,
-                        m as i32,
+                        if trans { CblasTrans } else { CblasNoTrans },
+                        rows as i32,
+                        columns as i32,
                         1.,
                         a.as_ptr(),
                         lda as i32,
-                        b.as_ptr(),
+                        x.as_ptr(),
                         1,
                         0.,
-                        buffer.as_ptr() as *mut $t,
+                        y.as_ptr() as *mut $t,
                         1,
                     )
                 }
@@ -169,6 +280,150 @@ macro_rules! impl_norm {
     };
 }
 
+macro_rules! impl_trsv {
+    ($t: ty, $blas_fn: ident) => {
+        /// Triangular solve using trsv.
+        impl operations::TriangularSolve<$t> for Blas {
+            fn triangular_solve<const LEN: usize, const N: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                x: &mut impl StaticVec<$t, N>,
+                upper: bool,
+                unit_diagonal: bool,
+            ) {
+                use cblas_sys::{CBLAS_DIAG::*, CBLAS_UPLO::*};
+                debug_assert_eq!(N * N, LEN);
+                unsafe {
+                    cblas_sys::$blas_fn(
+                        cblas_sys::CBLAS_LAYOUT::CblasRowMajor,
+                        if upper { CblasUpper } else { CblasLower },
+                        cblas_sys::CBLAS_TRANSPOSE::CblasNoTrans,
+                        if unit_diagonal {
+                            CblasUnit
+                        } else {
+                            CblasNonUnit
+                        },
+                        N as i32,
+                        a.as_ptr(),
+                        N as i32,
+                        x.as_mut_ptr(),
+                        1,
+                    )
+                }
+            }
+        }
+    };
+}
+
+macro_rules! impl_symv {
+    ($t: ty, $blas_fn: ident) => {
+        impl operations::SymmetricMatrixVectorMul<$t> for Blas {
+            fn symv<const N: usize, const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                x: &impl StaticVec<$t, N>,
+                y: &mut impl StaticVec<$t, N>,
+                upper: bool,
+            ) {
+                use cblas_sys::CBLAS_UPLO::*;
+                debug_assert_eq!(N * N, LEN);
+                unsafe {
+                    cblas_sys::$blas_fn(
+                        cblas_sys::CBLAS_LAYOUT::CblasRowMajor,
+                        if upper { CblasUpper } else { CblasLower },
+                        N as i32,
+                        1.,
+                        a.as_ptr(),
+                        N as i32,
+                        x.as_ptr(),
+                        1,
+                        0.,
+                        y.as_mut_ptr(),
+                        1,
+                    )
+                }
+            }
+        }
+    };
+}
+
+impl Blas {
+    /// Matrix-vector multiplication for symmetric matricies, using symv.
+    /// This is faster than [`Matrix::vector_mul`], as only the upper triangle of `a` is read.
+    ///
+    /// The caller is responsible for `a` being symmetric.
+    /// In debug builds some of the elements are checked.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 2, 1, 1, 3].matrix::<slas_backend::Blas, 2, 2>();
+    /// assert_eq!(slas_backend::Blas.symmetric_vector_mul(&a, &[1., 1.]), [3., 4.]);
+    /// ```
+    pub fn symmetric_vector_mul<
+        T: Float + std::fmt::Debug,
+        U: StaticVec<T, LEN>,
+        const N: usize,
+        const LEN: usize,
+    >(
+        &self,
+        a: &Matrix<T, U, Blas, LEN, false, MatrixShape<N, N>>,
+        x: &impl StaticVec<T, N>,
+    ) -> [T; N]
+    where
+        Blas: Backend<T> + operations::SymmetricMatrixVectorMul<T>,
+    {
+        if cfg!(debug_assertions) {
+            for i in 0..N {
+                let j = (i * 7 + 1) % N;
+                debug_assert_eq!(a[(i, j)], a[(j, i)], "Matrix is not symmetric");
+            }
+        }
+
+        let mut y = [T::_0; N];
+        operations::SymmetricMatrixVectorMul::symv::<N, LEN>(self, a.vec_ref(), x, &mut y, true);
+        y
+    }
+}
+
+#[cfg(feature = "lapack")]
+macro_rules! impl_inverse {
+    ($($t: ty),*) => {$(
+        /// Matrix inverse using LAPACK's getrf and getri.
+        impl operations::Inverse<$t> for Blas {
+            fn inverse<const N: usize, const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                buffer: &mut impl StaticVec<$t, LEN>,
+                epsilon: $t,
+            ) -> bool {
+                use crate::lapack::Lapack;
+                debug_assert_eq!(N * N, LEN);
+
+                // LAPACK is column-major, so it will see the transpose of a.
+                // The inverse of the transpose is the transpose of the inverse,
+                // which means the result is the row-major inverse of a.
+                let buffer = buffer.mut_moo_ref();
+                **buffer = **a.moo_ref();
+
+                let mut ipiv = [0i32; N];
+                unsafe {
+                    if <$t>::getrf(N, N, buffer.as_mut_ptr(), N, ipiv.as_mut_ptr()) != 0 {
+                        return false;
+                    }
+                    for n in 0..N {
+                        if buffer[n * N + n].abs() < epsilon {
+                            return false;
+                        }
+                    }
+                    <$t>::getri(N, buffer.as_mut_ptr(), N, ipiv.as_ptr()) == 0
+                }
+            }
+        }
+    )*};
+}
+
 impl_gemm!(f32: cblas_sgemm cblas_sgemv);
 impl_gemm!(f64: cblas_dgemm cblas_dgemv);
 //impl_gemm!(Complex<f32>, cblas_cgemm);
@@ -177,18 +432,51 @@ impl_gemm!(f64: cblas_dgemm cblas_dgemv);
 //impl_gemv!(f64, cblas_dgemv);
 //impl_gemv!(Complex<f32>, cblas_cgemv);
 
+#[cfg(feature = "lapack")]
+impl_inverse!(f32, f64);
+
+impl_trsv!(f32, cblas_strsv);
+impl_trsv!(f64, cblas_dtrsv);
+
+impl_symv!(f32, cblas_ssymv);
+impl_symv!(f64, cblas_dsymv);
+
 impl_dot!(f32, cblas_sdot);
 impl_dot!(f64, cblas_ddot);
 
-impl_dot_comp!(f32, cblas_cdotu_sub);
-impl_dot_comp!(f64, cblas_zdotu_sub);
+impl_weighted_dot!(f32, cblas_sdot);
+impl_weighted_dot!(f64, cblas_ddot);
+
+impl_axpy!(f32, cblas_saxpy);
+impl_axpy!(f64, cblas_daxpy);
+
+impl_scal!(f32, cblas_sscal);
+impl_scal!(f64, cblas_dscal);
+
+impl_abs!(f32, cblas_sasum, cblas_isamax);
+impl_abs!(f64, cblas_dasum, cblas_idamax);
+
+impl_dot_comp!(f32, cblas_cdotu_sub, cblas_cdotc_sub);
+impl_dot_comp!(f64, cblas_zdotu_sub, cblas_zdotc_sub);
 
 impl_norm!(f32, f32, f32, cblas_snrm2);
 impl_norm!(f64, f64, f64, cblas_dnrm2);
 impl_norm!(Complex<f32>, [f32; 2], f32, cblas_scnrm2);
 impl_norm!(Complex<f64>, [f64; 2], f64, cblas_dznrm2);
 
-impl Backend<f32> for Blas {}
-impl Backend<f64> for Blas {}
-impl Backend<Complex<f32>> for Blas {}
-impl Backend<Complex<f64>> for Blas {}
+impl_backend!(Blas: f32, f64 => [
+    DotProduct,
+    WeightedDot,
+    Normalize,
+    Axpy,
+    ScaleInPlace,
+    AbsSum,
+    AbsArgmax,
+    MatrixMul,
+    MatrixVectorMul,
+    SymmetricMatrixVectorMul,
+    TriangularSolve,
+    #[cfg(feature = "lapack")]
+    Inverse,
+]);
+impl_backend!(Blas: Complex<f32>, Complex<f64> => [DotProduct, InnerProduct, Normalize]);
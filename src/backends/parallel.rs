@@ -0,0 +1,98 @@
+/// A multithreaded slas backend, using [rayon](https://lib.rs/rayon).
+/// Vectors are split into chunks, that are processed in parallel with the simd implementations from [`Rust`].
+///
+/// This is only faster than the other backends for very large vectors.
+/// Only available with the `parallel` feature.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let a = moo![on slas_backend::Rayon:f32: 0..4];
+/// assert_eq!(a.dot(&[1., 2., 3., 4.].moo_ref().static_backend()), 20.);
+/// ```
+#[derive(Default, Clone, Copy)]
+pub struct Rayon;
+use super::*;
+use ::rayon::prelude::*;
+
+/// Number of elements processed by each task.
+const CHUNK_LEN: usize = 4096;
+
+macro_rules! impl_rayon {
+    ($($t: ty),*) => {$(
+        impl operations::DotProduct<$t> for Rayon {
+            fn dot<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                b: &impl StaticVec<$t, LEN>,
+            ) -> $t {
+                a.moo_ref()
+                    .par_chunks(CHUNK_LEN)
+                    .zip(b.moo_ref().par_chunks(CHUNK_LEN))
+                    .map(|(a, b)| {
+                        if a.len() == CHUNK_LEN {
+                            operations::DotProduct::<$t>::dot(
+                                &Rust,
+                                a.moo_ref::<CHUNK_LEN>(),
+                                b.moo_ref::<CHUNK_LEN>(),
+                            )
+                        } else {
+                            a.iter().zip(b.iter()).map(|(a, b)| a * b).sum()
+                        }
+                    })
+                    .sum()
+            }
+        }
+
+        impl operations::Normalize<$t> for Rayon {
+            type NormOutput = $t;
+            fn norm<const LEN: usize>(&self, a: &impl StaticVec<$t, LEN>) -> $t {
+                operations::DotProduct::dot(self, a, a).sqrt_()
+            }
+
+            fn normalize<const LEN: usize>(&self, a: &mut impl StaticVec<$t, LEN>) {
+                let norm = operations::Normalize::norm(self, a);
+                a.mut_moo_ref().par_iter_mut().for_each(|n| *n /= norm);
+            }
+        }
+
+        impl_backend!(Rayon: $t => [DotProduct, Normalize, Addition, Multiplication]);
+    )*};
+}
+
+macro_rules! impl_rayon_basic_op {
+    ($op: ident, $fn: ident, $float_op: tt, $($t: ty),*) => {$(
+        impl operations::$op<$t> for Rayon {
+            fn $fn<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                b: &impl StaticVec<$t, LEN>,
+                c: &mut impl StaticVec<$t, LEN>,
+            ) {
+                c.mut_moo_ref()
+                    .par_chunks_mut(CHUNK_LEN)
+                    .zip(a.moo_ref().par_chunks(CHUNK_LEN))
+                    .zip(b.moo_ref().par_chunks(CHUNK_LEN))
+                    .for_each(|((c, a), b)| {
+                        if c.len() == CHUNK_LEN {
+                            operations::$op::$fn(
+                                &Rust,
+                                a.moo_ref::<CHUNK_LEN>(),
+                                b.moo_ref::<CHUNK_LEN>(),
+                                c.mut_moo_ref::<CHUNK_LEN>(),
+                            )
+                        } else {
+                            c.iter_mut()
+                                .zip(a.iter().zip(b.iter()))
+                                .for_each(|(c, (a, b))| *c = a $float_op b)
+                        }
+                    });
+            }
+        }
+    )*};
+}
+
+impl_rayon!(f32, f64);
+impl_rayon_basic_op!(Addition, add, +, f32, f64);
+impl_rayon_basic_op!(Multiplication, mul, *, f32, f64);
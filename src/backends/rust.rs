@@ -6,6 +6,8 @@ use operations::*;
 use std::mem::transmute;
 use std::simd::Simd;
 use std::simd::SimdFloat;
+use std::simd::SimdInt;
+use std::simd::SimdOrd;
 
 macro_rules! impl_dot {
     ($t: ty) => {
@@ -42,6 +44,141 @@ macro_rules! impl_dot {
     };
 }
 
+macro_rules! impl_inner_product {
+    ($($t: ty),*) => {$(
+        /// Hermitian inner product for two complex vectors.
+        impl InnerProduct<Complex<$t>> for Rust {
+            fn inner_product<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<Complex<$t>, LEN>,
+                b: &impl StaticVec<Complex<$t>, LEN>,
+            ) -> Complex<$t> {
+                let (a, b) = (a.moo_ref(), b.moo_ref());
+                let mut sum = Complex::<$t> { re: 0., im: 0. };
+                for n in 0..LEN {
+                    sum.re += a[n].re * b[n].re + a[n].im * b[n].im;
+                    sum.im += a[n].re * b[n].im - a[n].im * b[n].re;
+                }
+                sum
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_axpy_scal {
+    ($($t: ty),*) => {$(
+        impl Axpy<$t> for Rust {
+            fn axpy<const LEN: usize>(
+                &self,
+                alpha: $t,
+                x: &impl StaticVec<$t, LEN>,
+                y: &mut impl StaticVec<$t, LEN>,
+            ) -> () {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let alpha_simd = Simd::<$t, LANES>::splat(alpha);
+                let y_ptr: *mut [$t; LANES] = unsafe { transmute(y.as_mut_ptr()) };
+
+                for n in 0..LEN / LANES {
+                    unsafe {
+                        *y_ptr.add(n) = (alpha_simd
+                            * Simd::<$t, LANES>::from_slice(x.static_slice_unchecked::<LANES>(n * LANES))
+                            + Simd::<$t, LANES>::from_array(*y_ptr.add(n)))
+                        .to_array();
+                    }
+                }
+
+                for n in LEN - (LEN % LANES)..LEN {
+                    unsafe { *y.get_unchecked_mut(n) += alpha * *x.get_unchecked(n) };
+                }
+            }
+        }
+
+        impl ScaleInPlace<$t> for Rust {
+            fn scal<const LEN: usize>(&self, alpha: $t, x: &mut impl StaticVec<$t, LEN>) -> () {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let alpha_simd = Simd::<$t, LANES>::splat(alpha);
+                let x_ptr: *mut [$t; LANES] = unsafe { transmute(x.as_mut_ptr()) };
+
+                for n in 0..LEN / LANES {
+                    unsafe {
+                        *x_ptr.add(n) = (alpha_simd * Simd::<$t, LANES>::from_array(*x_ptr.add(n))).to_array();
+                    }
+                }
+
+                for n in LEN - (LEN % LANES)..LEN {
+                    unsafe { *x.get_unchecked_mut(n) *= alpha };
+                }
+            }
+        }
+
+        paste!{
+            #[test]
+            fn [< axpy_ $t >](){
+                let x = moo![$t: 0..13];
+                let mut y = [1.; 13];
+                Axpy::axpy(&Rust, 2., &x, &mut y);
+
+                for n in 0..13{
+                    assert_eq!(y[n], 2. * x[n] + 1.);
+                }
+            }
+
+            #[test]
+            fn [< scal_ $t >](){
+                let mut x = moo![$t: 0..13];
+                ScaleInPlace::scal(&Rust, -3., &mut x);
+
+                for n in 0..13{
+                    assert_eq!(x[n], -3. * n as $t);
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_weighted_dot {
+    ($($t: ty),*) => {$(
+        impl WeightedDot<$t> for Rust {
+            fn weighted_dot<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                b: &impl StaticVec<$t, LEN>,
+                w: &impl StaticVec<$t, LEN>,
+            ) -> $t {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let mut sum = Simd::<$t, LANES>::from_array([0.; LANES]);
+                for n in 0..LEN / LANES {
+                    sum += unsafe {
+                        Simd::from_slice(w.static_slice_unchecked::<LANES>(n * LANES))
+                            * Simd::from_slice(a.static_slice_unchecked::<LANES>(n * LANES))
+                            * Simd::from_slice(b.static_slice_unchecked::<LANES>(n * LANES))
+                    }
+                }
+                let mut sum = sum.reduce_sum();
+                for n in LEN - (LEN % LANES)..LEN {
+                    sum += unsafe { w.get_unchecked(n) * a.get_unchecked(n) * b.get_unchecked(n) }
+                }
+                sum
+            }
+        }
+
+        paste!{
+            #[test]
+            fn [< weighted_dot_ $t >](){
+                let a = moo![$t: 0..13];
+                let b = [2.; 13];
+                let w = moo![$t: 0..13];
+
+                let expected: $t = (0..13).map(|n| 2. * (n * n) as $t).sum();
+                assert_eq!(WeightedDot::weighted_dot(&Rust, &a, &b, &w), expected);
+            }
+        }
+    )*};
+}
+
 macro_rules! impl_basic_op {
     ($op: ident, $fn: ident, $float_op: tt, $op_assign: ident, $($t: ty),*) => {$(
         /// Basic element wise operators are implemented for all vectors on the rust backend.
@@ -56,19 +193,29 @@ macro_rules! impl_basic_op {
             ) -> () {
                 const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
 
-                let out_ptr: *mut [$t; LANES] = unsafe{transmute(c.as_mut_ptr())};
+                let mut op = |use_simd: bool| {
+                    let simd_end = if use_simd { LEN - (LEN % LANES) } else { 0 };
+                    let out_ptr: *mut [$t; LANES] = unsafe{transmute(c.as_mut_ptr())};
 
-                for n in 0..LEN / LANES {
-                    unsafe {
-                            *out_ptr.add(n) = transmute(
-                                Simd::<$t, LANES>::from_slice(a.static_slice_unchecked::<LANES>(n * LANES)) $float_op
-                                Simd::<$t, LANES>::from_slice(b.static_slice_unchecked::<LANES>(n * LANES)))
+                    for n in 0..simd_end / LANES {
+                        unsafe {
+                                *out_ptr.add(n) = transmute(
+                                    Simd::<$t, LANES>::from_slice(a.static_slice_unchecked::<LANES>(n * LANES)) $float_op
+                                    Simd::<$t, LANES>::from_slice(b.static_slice_unchecked::<LANES>(n * LANES)))
+                        }
                     }
-                }
 
-                for n in LEN - (LEN % LANES)..LEN {
-                    unsafe { *c.get_unchecked_mut(n) = *a.get_unchecked(n) $float_op *b.get_unchecked(n) };
+                    for n in simd_end..LEN {
+                        unsafe { *c.get_unchecked_mut(n) = *a.get_unchecked(n) $float_op *b.get_unchecked(n) };
+                    }
+                };
+                let use_simd = LEN >= crate::config::SIMD_IN_ADD_IF_LEN_GE;
+
+                #[cfg(feature = "profile")]
+                if crate::profile::is_active() {
+                    return crate::profile::compare(crate::profile::Knob::SimdInAdd, LEN, use_simd, op);
                 }
+                op(use_simd)
             }
         }
 
@@ -111,6 +258,117 @@ macro_rules! impl_basic_op {
     )*};
 }
 
+macro_rules! impl_minmax_op {
+    ($op: ident, $fn: ident, $simd_fn: ident, $scalar_fn: ident, $($t: ty),*) => {$(
+        impl $op<$t> for Rust {
+            fn $fn<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                b: &impl StaticVec<$t, LEN>,
+                c: &mut impl StaticVec<$t, LEN>,
+            ) -> () {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let out_ptr: *mut [$t; LANES] = unsafe{transmute(c.as_mut_ptr())};
+
+                for n in 0..LEN / LANES {
+                    unsafe {
+                        let a = Simd::<$t, LANES>::from_slice(a.static_slice_unchecked::<LANES>(n * LANES));
+                        let b = Simd::<$t, LANES>::from_slice(b.static_slice_unchecked::<LANES>(n * LANES));
+                        *out_ptr.add(n) = a.$simd_fn(b).to_array();
+                    }
+                }
+
+                for n in LEN - (LEN % LANES)..LEN {
+                    unsafe { *c.get_unchecked_mut(n) = (*a.get_unchecked(n)).$scalar_fn(*b.get_unchecked(n)) };
+                }
+            }
+        }
+
+        paste!{
+            #[test]
+            fn [< $fn _ $t >](){
+                let mut a = [0 as $t; 13];
+                let mut b = [0 as $t; 13];
+                for n in 0..13 {
+                    a[n] = n as $t - 6 as $t;
+                    b[n] = 6 as $t - n as $t;
+                }
+                let mut c = [0 as $t; 13];
+                $op::$fn(&Rust, &a, &b, &mut c);
+
+                for n in 0..13{
+                    assert_eq!(c[n], a[n].$scalar_fn(b[n]));
+                }
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_int_op {
+    ($op: ident, $fn: ident, |$a: ident, $b: ident| $simd_op: expr, $($t: ty),*) => {$(
+        /// Wrapping and saturating element-wise operators for integer vectors.
+        /// Call `a.wrapping_add(&b)` or `a.saturating_add(&b)` to use them.
+        impl $op<$t> for Rust {
+            fn $fn<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                b: &impl StaticVec<$t, LEN>,
+                c: &mut impl StaticVec<$t, LEN>,
+            ) -> () {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let out_ptr: *mut [$t; LANES] = unsafe{transmute(c.as_mut_ptr())};
+
+                for n in 0..LEN / LANES {
+                    unsafe {
+                        let $a = Simd::<$t, LANES>::from_slice(a.static_slice_unchecked::<LANES>(n * LANES));
+                        let $b = Simd::<$t, LANES>::from_slice(b.static_slice_unchecked::<LANES>(n * LANES));
+                        *out_ptr.add(n) = ($simd_op).to_array();
+                    }
+                }
+
+                for n in LEN - (LEN % LANES)..LEN {
+                    unsafe { *c.get_unchecked_mut(n) = a.get_unchecked(n).$fn(*b.get_unchecked(n)) };
+                }
+            }
+        }
+
+        paste!{
+            impl<'a, const LEN: usize> StaticVecUnion<'a, $t, LEN> {
+                /// Integer element-wise vector operations, implemented automatically with macro.
+                #[inline(always)]
+                pub fn $fn(&self, other: &Self) -> Self{
+                    let mut buffer = StaticVecUnion { owned: [0 as $t; LEN] };
+                    $op::$fn(&Rust, self, other, &mut buffer);
+                    buffer
+                }
+
+                /// Integer element-wise vector operations (buffered), implemented automatically with macro.
+                #[inline(always)]
+                pub fn [<$fn _into>]<'b>(&self, other: &Self, buffer: MutStaticVecRef<'b, $t, LEN>){
+                    $op::$fn(&Rust, self, other, buffer);
+                }
+            }
+
+            #[test]
+            fn [< int_ $fn _ $t >](){
+                let mut a = [0 as $t; 13];
+                let mut b = [0 as $t; 13];
+                for n in 0..13 {
+                    a[n] = <$t>::MAX - n as $t;
+                    b[n] = n as $t - 6;
+                }
+                let c = a.moo_ref().$fn(b.moo_ref());
+
+                for n in 0..13{
+                    assert_eq!(a[n].$fn(b[n]), c[n]);
+                }
+            }
+        }
+    )*};
+}
+
 macro_rules! impl_norm {
     ($t: ty) => {
         impl Normalize<$t> for Rust {
@@ -148,6 +406,161 @@ macro_rules! impl_norm {
     };
 }
 
+impl<T: Float> TriangularSolve<T> for Rust {
+    fn triangular_solve<const LEN: usize, const N: usize>(
+        &self,
+        a: &impl StaticVec<T, LEN>,
+        x: &mut impl StaticVec<T, N>,
+        upper: bool,
+        unit_diagonal: bool,
+    ) {
+        debug_assert_eq!(N * N, LEN);
+        let a = a.moo_ref();
+        let x = x.mut_moo_ref();
+
+        let mut solve_row = |i: usize, j: std::ops::Range<usize>| {
+            let mut sum = x[i];
+            for j in j {
+                sum -= a[i * N + j] * x[j];
+            }
+            x[i] = if unit_diagonal {
+                sum
+            } else {
+                sum / a[i * N + i]
+            };
+        };
+
+        if upper {
+            for i in (0..N).rev() {
+                solve_row(i, i + 1..N)
+            }
+        } else {
+            for i in 0..N {
+                solve_row(i, 0..i)
+            }
+        }
+    }
+}
+
+impl<T: Float + PartialOrd> Inverse<T> for Rust {
+    fn inverse<const N: usize, const LEN: usize>(
+        &self,
+        a: &impl StaticVec<T, LEN>,
+        buffer: &mut impl StaticVec<T, LEN>,
+        epsilon: T,
+    ) -> bool {
+        use crate::decompose::{abs, lu_decompose};
+
+        let (l, u, p, _) = lu_decompose::<T, N, LEN>(**a.moo_ref());
+        for n in 0..N {
+            if abs(u[n * N + n]) < epsilon {
+                return false;
+            }
+        }
+
+        let buffer = buffer.mut_moo_ref();
+        for c in 0..N {
+            let mut x = [T::_0; N];
+            for n in 0..N {
+                if p[n] == c {
+                    x[n] = T::_1;
+                }
+            }
+            TriangularSolve::triangular_solve(self, &l, &mut x, false, true);
+            TriangularSolve::triangular_solve(self, &u, &mut x, true, false);
+            for r in 0..N {
+                buffer[r * N + c] = x[r];
+            }
+        }
+        true
+    }
+}
+
+macro_rules! impl_gemm {
+    ($($t: ty),*) => {$(
+        /// Matrix multiplication on the rust backend, using a simple loop.
+        /// The blas backend also uses this for small matricies, see `slas::config::BLAS_IN_GEMM_IF_SIZE_GE`.
+        impl MatrixMul<$t> for Rust {
+            fn matrix_mul<
+                A: StaticVec<$t, ALEN>,
+                B: StaticVec<$t, BLEN>,
+                C: StaticVec<$t, CLEN>,
+                const ALEN: usize,
+                const BLEN: usize,
+                const CLEN: usize,
+            >(
+                &self,
+                a: &A,
+                b: &B,
+                buffer: &mut C,
+                m: usize,
+                n: usize,
+                k: usize,
+                lda: usize,
+                ldb: usize,
+                ldc: usize,
+                a_trans: bool,
+                b_trans: bool,
+            ) where
+                A: Sized,
+                B: Sized,
+            {
+                for i in 0..m {
+                    for j in 0..n {
+                        let mut sum = 0.;
+                        for p in 0..k {
+                            unsafe {
+                                let a = if a_trans { a.get_unchecked(p * lda + i) } else { a.get_unchecked(i * lda + p) };
+                                let b = if b_trans { b.get_unchecked(j * ldb + p) } else { b.get_unchecked(p * ldb + j) };
+                                sum += a * b;
+                            }
+                        }
+                        unsafe { *buffer.get_unchecked_mut(i * ldc + j) = sum };
+                    }
+                }
+            }
+        }
+    )*};
+}
+
+impl<T: Float> MatrixVectorMul<T> for Rust {
+    fn matrix_vector_mul<
+        A: StaticVec<T, ALEN>,
+        X: StaticVec<T, XLEN>,
+        Y: StaticVec<T, YLEN>,
+        const ALEN: usize,
+        const XLEN: usize,
+        const YLEN: usize,
+    >(
+        &self,
+        a: &A,
+        x: &X,
+        y: &mut Y,
+        m: usize,
+        n: usize,
+        lda: usize,
+        trans: bool,
+    ) where
+        A: Sized,
+        X: Sized,
+    {
+        for i in 0..m {
+            let mut sum = T::_0;
+            for j in 0..n {
+                let a = unsafe {
+                    if trans {
+                        *a.get_unchecked(j * lda + i)
+                    } else {
+                        *a.get_unchecked(i * lda + j)
+                    }
+                };
+                sum += a * unsafe { *x.get_unchecked(j) };
+            }
+            unsafe { *y.get_unchecked_mut(i) = sum };
+        }
+    }
+}
+
 impl<T: Copy> Transpose<T> for Rust {
     fn transpose_inplace<const LEN: usize>(
         &self,
@@ -176,18 +589,391 @@ impl<T: Copy> Transpose<T> for Rust {
     }
 }
 
+macro_rules! impl_lp_norm {
+    ($($t: ty),*) => {$(
+        impl LpNorm<$t> for Rust {
+            fn l1_norm<const LEN: usize>(&self, a: &impl StaticVec<$t, LEN>) -> $t {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let mut sum = Simd::<$t, LANES>::from_array([0.; LANES]);
+                for n in 0..LEN / LANES {
+                    sum += unsafe { Simd::from_slice(a.static_slice_unchecked::<LANES>(n * LANES)).abs() }
+                }
+                let mut sum = sum.reduce_sum();
+                for n in LEN - (LEN % LANES)..LEN {
+                    sum += unsafe { a.get_unchecked(n).abs() }
+                }
+                sum
+            }
+
+            fn linf_norm<const LEN: usize>(&self, a: &impl StaticVec<$t, LEN>) -> $t {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let mut max = Simd::<$t, LANES>::from_array([0.; LANES]);
+                for n in 0..LEN / LANES {
+                    max = max.simd_max(unsafe { Simd::from_slice(a.static_slice_unchecked::<LANES>(n * LANES)).abs() })
+                }
+                let mut max = max.reduce_max();
+                for n in LEN - (LEN % LANES)..LEN {
+                    max = max.max(unsafe { a.get_unchecked(n).abs() })
+                }
+                max
+            }
+
+            fn p_norm<const LEN: usize>(&self, a: &impl StaticVec<$t, LEN>, p: $t) -> $t {
+                a.moo_ref().iter().map(|n| n.abs().powf(p)).sum::<$t>().powf(1. / p)
+            }
+        }
+
+        impl<'a, const LEN: usize> StaticVecUnion<'a, $t, LEN> {
+            /// Sum of absolute values (L1 norm), implemented automatically with macro.
+            #[inline(always)]
+            pub fn l1_norm(&self) -> $t {
+                LpNorm::l1_norm(&Rust, self)
+            }
+
+            /// Largest absolute value (L-infinity norm), implemented automatically with macro.
+            #[inline(always)]
+            pub fn linf_norm(&self) -> $t {
+                LpNorm::linf_norm(&Rust, self)
+            }
+
+            /// Generalised p-norm, implemented automatically with macro.
+            #[inline(always)]
+            pub fn p_norm(&self, p: $t) -> $t {
+                LpNorm::p_norm(&Rust, self, p)
+            }
+        }
+
+        paste!{
+            #[test]
+            fn [< lp_norm_ $t >](){
+                assert_eq!(moo![$t: 3, 4].l1_norm(), 7.);
+                assert_eq!(moo![$t: 3, -5].linf_norm(), 5.);
+                assert!((moo![$t: 3, -4].p_norm(2.) - 5.).abs() < 1e-5);
+
+                let mut a = moo![$t: 0..13];
+                a.iter_mut().for_each(|n| *n -= 6.);
+                assert_eq!(a.l1_norm(), 42.);
+                assert_eq!(a.linf_norm(), 6.);
+                assert!((a.p_norm(1.) - 42.).abs() < 1e-4);
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_abs_blas1 {
+    ($($t: ty),*) => {$(
+        impl AbsSum<$t> for Rust {
+            fn asum<const LEN: usize>(&self, a: &impl StaticVec<$t, LEN>) -> $t {
+                LpNorm::l1_norm(self, a)
+            }
+        }
+
+        impl AbsArgmax<$t> for Rust {
+            fn iamax<const LEN: usize>(&self, a: &impl StaticVec<$t, LEN>) -> usize {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let mut max: $t = -1.;
+                let mut index = 0;
+                for n in 0..LEN / LANES {
+                    let chunk = unsafe { Simd::<$t, LANES>::from_slice(a.static_slice_unchecked::<LANES>(n * LANES)).abs() };
+                    let chunk_max = chunk.reduce_max();
+                    // Only the first chunk containing the largest value is searched for its index.
+                    if chunk_max > max {
+                        max = chunk_max;
+                        index = n * LANES + chunk.to_array().iter().position(|&v| v == chunk_max).unwrap();
+                    }
+                }
+                for n in LEN - (LEN % LANES)..LEN {
+                    let v = unsafe { a.get_unchecked(n).abs() };
+                    if v > max {
+                        max = v;
+                        index = n;
+                    }
+                }
+                index
+            }
+        }
+
+        paste!{
+            #[test]
+            fn [< asum_iamax_ $t >](){
+                let mut a = moo![$t: 0..13];
+                a.iter_mut().for_each(|n| *n -= 6.);
+                assert_eq!(AbsSum::asum(&Rust, &a), 42.);
+                assert_eq!(AbsArgmax::iamax(&Rust, &a), 0);
+
+                a[9] = -20.;
+                a[11] = 20.;
+                assert_eq!(AbsArgmax::iamax(&Rust, &a), 9);
+                assert_eq!(AbsArgmax::iamax(&Rust, &[1 as $t, -3., 3.]), 1);
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_abs {
+    ($($t: ty),*) => {$(
+        impl<'a, const LEN: usize> StaticVecUnion<'a, $t, LEN> {
+            /// Element-wise absolute value, implemented automatically with macro.
+            /// The simd implementation just clears the sign bit of each element.
+            pub fn abs(&self) -> StaticVecUnion<'static, $t, LEN> {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let mut out = StaticVecUnion { owned: [0.; LEN] };
+                let out_ptr: *mut [$t; LANES] = unsafe { transmute(out.as_mut_ptr()) };
+
+                for n in 0..LEN / LANES {
+                    unsafe {
+                        *out_ptr.add(n) =
+                            Simd::<$t, LANES>::from_slice(self.static_slice_unchecked::<LANES>(n * LANES)).abs().to_array();
+                    }
+                }
+
+                for n in LEN - (LEN % LANES)..LEN {
+                    out[n] = self[n].abs();
+                }
+                out
+            }
+        }
+
+        impl<'a, const LEN: usize> StaticVecUnion<'a, Complex<$t>, LEN> {
+            /// Element-wise modulus of complex vector, implemented automatically with macro.
+            pub fn abs_complex(&self) -> StaticVecUnion<'static, $t, LEN> {
+                let mut out = StaticVecUnion { owned: [0.; LEN] };
+                for n in 0..LEN {
+                    out[n] = self[n].re.hypot(self[n].im);
+                }
+                out
+            }
+        }
+
+        paste!{
+            #[test]
+            fn [< abs_ $t >](){
+                let mut a = moo![$t: 0..13];
+                a.iter_mut().for_each(|n| *n -= 6.);
+                let b = a.abs();
+                for n in 0..13{
+                    assert_eq!(a[n].abs(), b[n]);
+                }
+                assert_eq!(*moo![$t: -0., -1.5, 2].abs(), [0., 1.5, 2.]);
+
+                let c = [Complex::<$t>{ re: 3., im: -4. }, Complex{ re: -1., im: 0. }];
+                assert_eq!(*c.moo_ref().abs_complex(), [5., 1.]);
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_transcendental {
+    ($t: ty, $sleef_map: ident, $($fn: ident: $std_fn: ident / $sleef_fn: ident),*) => {
+        /// Element-wise transcendental functions.
+        /// This means you can call `a.vsin()` to take the sine of each element in a vector.
+        /// This also works with `.vcos`, `.vexp` and `.vln`.
+        impl VectorizedTranscendental<$t> for Rust {$(
+            fn $fn<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<$t, LEN>,
+                buffer: &mut impl StaticVec<$t, LEN>,
+            ) -> () {
+                #[allow(unused_mut)]
+                let mut start = 0;
+
+                #[cfg(all(feature = "sleef", target_arch = "x86_64", target_feature = "avx2"))]
+                unsafe {
+                    start = sleef::$sleef_map::<LEN>(a.as_ptr(), buffer.as_mut_ptr(), sleef_sys::$sleef_fn);
+                }
+
+                for n in start..LEN {
+                    unsafe { *buffer.get_unchecked_mut(n) = a.get_unchecked(n).$std_fn() };
+                }
+            }
+        )*}
+
+        paste!{
+            impl<'a, const LEN: usize> StaticVecUnion<'a, $t, LEN> {$(
+                /// Element-wise transcendental function, implemented automatically with macro.
+                #[inline(always)]
+                pub fn $fn(&self) -> Self{
+                    let mut buffer = StaticVecUnion { owned: [0.; LEN] };
+                    VectorizedTranscendental::$fn(&Rust, self, &mut buffer);
+                    buffer
+                }
+
+                /// Element-wise transcendental function (buffered), implemented automatically with macro.
+                #[inline(always)]
+                pub fn [<$fn _into>]<'b>(&self, buffer: MutStaticVecRef<'b, $t, LEN>){
+                    VectorizedTranscendental::$fn(&Rust, self, buffer);
+                }
+            )*}
+
+            $(
+            #[test]
+            fn [< transcendental_ $fn _ $t >](){
+                let a = moo![$t: 1..13];
+                let b = a.$fn();
+                let mut c = [0.; 12];
+                a.[<$fn _into>](c.mut_moo_ref());
+
+                for n in 0..12{
+                    assert!((a[n].$std_fn() - b[n]).abs() < 1e-5 * a[n].$std_fn().abs().max(1.));
+                    assert_eq!(b[n], c[n]);
+                }
+            }
+            )*
+        }
+    };
+}
+
+/// SIMD transcendental functions from [SLEEF](https://sleef.org).
+#[cfg(all(feature = "sleef", target_arch = "x86_64", target_feature = "avx2"))]
+mod sleef {
+    use std::arch::x86_64::*;
+
+    /// Apply `f` to all full simd chunks of `a`, and return the index of the first element that was not processed.
+    pub unsafe fn map_f32<const LEN: usize>(
+        a: *const f32,
+        buffer: *mut f32,
+        f: unsafe extern "C" fn(__m256) -> __m256,
+    ) -> usize {
+        for n in 0..LEN / 8 {
+            _mm256_storeu_ps(buffer.add(n * 8), f(_mm256_loadu_ps(a.add(n * 8))));
+        }
+        LEN - LEN % 8
+    }
+
+    /// Apply `f` to all full simd chunks of `a`, and return the index of the first element that was not processed.
+    pub unsafe fn map_f64<const LEN: usize>(
+        a: *const f64,
+        buffer: *mut f64,
+        f: unsafe extern "C" fn(__m256d) -> __m256d,
+    ) -> usize {
+        for n in 0..LEN / 4 {
+            _mm256_storeu_pd(buffer.add(n * 4), f(_mm256_loadu_pd(a.add(n * 4))));
+        }
+        LEN - LEN % 4
+    }
+}
+
 impl_norm!(f32);
 impl_norm!(f64);
 
 impl_dot!(f32);
 impl_dot!(f64);
 
+impl_inner_product!(f32, f64);
+
+impl_gemm!(f32, f64);
+
+impl_axpy_scal!(f32, f64);
+
+impl_weighted_dot!(f32, f64);
+
 impl_basic_op!(Addition, add, +, add_assign, f32, f64);
 impl_basic_op!(Multiplication, mul, *, mul_assign, f32, f64);
 impl_basic_op!(Divition, div, /, div_assign, f32, f64);
 impl_basic_op!(Subtraction, sub, -, sub_assign, f32, f64);
 
-impl Backend<f32> for Rust {}
-impl Backend<f64> for Rust {}
-impl Backend<Complex<f32>> for Rust {}
-impl Backend<Complex<f64>> for Rust {}
+impl_minmax_op!(
+    ElementwiseMax,
+    elementwise_max,
+    simd_max,
+    max,
+    f32,
+    f64,
+    i32,
+    i64
+);
+impl_minmax_op!(
+    ElementwiseMin,
+    elementwise_min,
+    simd_min,
+    min,
+    f32,
+    f64,
+    i32,
+    i64
+);
+
+impl_int_op!(WrappingAddition, wrapping_add, |a, b| a + b, i32, i64);
+impl_int_op!(WrappingSubtraction, wrapping_sub, |a, b| a - b, i32, i64);
+impl_int_op!(WrappingMultiplication, wrapping_mul, |a, b| a * b, i32, i64);
+impl_int_op!(
+    SaturatingAddition,
+    saturating_add,
+    |a, b| a.saturating_add(b),
+    i32,
+    i64
+);
+impl_int_op!(
+    SaturatingSubtraction,
+    saturating_sub,
+    |a, b| a.saturating_sub(b),
+    i32,
+    i64
+);
+impl_int_op!(
+    SaturatingMultiplication,
+    saturating_mul,
+    |a, b| Simd::from_array(std::array::from_fn(|n| a[n].saturating_mul(b[n]))),
+    i32,
+    i64
+);
+
+impl_lp_norm!(f32, f64);
+impl_abs!(f32, f64);
+impl_abs_blas1!(f32, f64);
+
+impl_transcendental!(
+    f32,
+    map_f32,
+    vsin: sin / Sleef_sinf8_u10,
+    vcos: cos / Sleef_cosf8_u10,
+    vexp: exp / Sleef_expf8_u10,
+    vln: ln / Sleef_logf8_u10
+);
+impl_transcendental!(
+    f64,
+    map_f64,
+    vsin: sin / Sleef_sind4_u10,
+    vcos: cos / Sleef_cosd4_u10,
+    vexp: exp / Sleef_expd4_u10,
+    vln: ln / Sleef_logd4_u10
+);
+
+impl_backend!(Rust: f32, f64 => [
+    DotProduct,
+    WeightedDot,
+    Normalize,
+    Axpy,
+    ScaleInPlace,
+    AbsSum,
+    AbsArgmax,
+    MatrixMul,
+    MatrixVectorMul,
+    TriangularSolve,
+    Inverse,
+    Transpose,
+    Addition,
+    Subtraction,
+    Multiplication,
+    Divition,
+    ElementwiseMax,
+    ElementwiseMin,
+    LpNorm,
+    VectorizedTranscendental,
+]);
+impl_backend!(Rust: Complex<f32>, Complex<f64> => [InnerProduct, Normalize, Transpose]);
+impl_backend!(Rust: i32, i64 => [
+    Transpose,
+    ElementwiseMax,
+    ElementwiseMin,
+    WrappingAddition,
+    WrappingSubtraction,
+    WrappingMultiplication,
+    SaturatingAddition,
+    SaturatingSubtraction,
+    SaturatingMultiplication,
+]);
@@ -0,0 +1,87 @@
+use super::*;
+use std::time::{Duration, Instant};
+
+/// A backend that runs every operation on `B`, and reports the name and duration of each operation to `L`.
+/// This makes it possible to profile or trace the operations of any backend,
+/// fx. `BackendWrapper<Blas, StderrLogger>` can be used anywhere `Blas` can.
+///
+/// Backends are created with [`Default::default`] whenever a vector or matrix is created with a static backend,
+/// so the logger is a type implementing [`BackendLogger`], instead of a closure.
+/// With the `()` logger, operations are not timed, and the wrapper has no overhead.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas_backend::{BackendLogger, BackendWrapper, Blas};
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static CALLS: AtomicUsize = AtomicUsize::new(0);
+///
+/// #[derive(Default)]
+/// struct CountingLogger;
+///
+/// impl BackendLogger for CountingLogger {
+///     fn log(&self, op: &str, _elapsed: std::time::Duration) {
+///         assert_eq!(op, "dot");
+///         CALLS.fetch_add(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let a = moo![on BackendWrapper<Blas, CountingLogger>: f32: 0..4];
+/// assert_eq!(a.dot(&[1., 2., 3., 4.].moo_ref().static_backend()), 20.);
+/// assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+/// ```
+#[derive(Default, Clone, Copy)]
+pub struct BackendWrapper<B, L: BackendLogger = StderrLogger> {
+    pub inner: B,
+    pub logger: L,
+}
+
+/// Receives the name and duration of every operation run on a [`BackendWrapper`].
+pub trait BackendLogger: Default {
+    /// If false, operations are not timed and [`BackendLogger::log`] is never called.
+    const ENABLED: bool = true;
+
+    fn log(&self, op: &str, elapsed: Duration);
+}
+
+/// Logger that does nothing.
+impl BackendLogger for () {
+    const ENABLED: bool = false;
+
+    fn log(&self, _op: &str, _elapsed: Duration) {}
+}
+
+/// Logger that prints every operation to stderr.
+#[derive(Default, Clone, Copy)]
+pub struct StderrLogger;
+
+impl BackendLogger for StderrLogger {
+    fn log(&self, op: &str, elapsed: Duration) {
+        eprintln!("slas: {op} took {elapsed:?}");
+    }
+}
+
+impl<B, L: BackendLogger> BackendWrapper<B, L> {
+    pub const fn new(inner: B, logger: L) -> Self {
+        Self { inner, logger }
+    }
+
+    /// Run `f`, and log how long it took as `op`.
+    #[inline(always)]
+    pub(crate) fn run<R>(&self, op: &str, f: impl FnOnce() -> R) -> R {
+        if !L::ENABLED {
+            return f();
+        }
+        let start = Instant::now();
+        let ret = f();
+        self.logger.log(op, start.elapsed());
+        ret
+    }
+}
+
+impl<T, B: Backend<T>, L: BackendLogger> Backend<T> for BackendWrapper<B, L> {
+    fn supports(&self, op: BackendOp) -> bool {
+        self.inner.supports(op)
+    }
+}
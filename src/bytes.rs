@@ -0,0 +1,130 @@
+//! Binary serialization of static vectors.
+//! Only available with the `bytemuck` feature.
+//!
+//! The format is 8 bytes of magic number (`b"SLASVEC\0"`), the size of an element in bytes as a little-endian `u32`,
+//! the number of elements as a little-endian `u32`, and then the raw bytes of the elements in little-endian order.
+//! On big-endian targets the bytes of each element are swapped, which assumes that the elements are primitives (fx. floats).
+//!
+//! Matricies can be serialized by serializing their data, and deserialized with [`StaticVec::matrix`].
+//!
+//! ## Example
+//! ```rust
+//! use slas::prelude::*;
+//!
+//! let v = moo![f32: 1..4];
+//! let bytes = v.to_bytes();
+//! assert_eq!(bytes.len(), 16 + 3 * 4);
+//!
+//! assert_eq!(StaticCowVec::<f32, 3>::from_bytes(&bytes).unwrap(), v);
+//! assert!(StaticCowVec::<f64, 3>::from_bytes(&bytes).is_err());
+//! assert!(StaticCowVec::<f32, 4>::from_bytes(&bytes).is_err());
+//! ```
+
+use crate::prelude::*;
+use bytemuck::Pod;
+use std::{convert::TryInto, mem::size_of};
+
+/// Magic number at the start of serialized vectors.
+pub const MAGIC: [u8; 8] = *b"SLASVEC\0";
+
+const HEADER_LEN: usize = 16;
+
+/// Error returned when bytes cannot be deserialized as a vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryDeserError {
+    /// The bytes do not start with [`MAGIC`].
+    Magic,
+    /// The size of the serialized elements is not the size of the element type.
+    ElementSize { expected: usize, found: usize },
+    /// The number of serialized elements is not the length of the vector.
+    Length { expected: usize, found: usize },
+    /// The number of bytes does not match the header.
+    ByteCount { expected: usize, found: usize },
+}
+
+impl std::fmt::Display for BinaryDeserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Magic => write!(f, "Bytes do not start with the slas magic number"),
+            Self::ElementSize { expected, found } => write!(
+                f,
+                "Expected elements of {expected} bytes, found elements of {found} bytes"
+            ),
+            Self::Length { expected, found } => {
+                write!(
+                    f,
+                    "Expected vector of length {expected}, found length {found}"
+                )
+            }
+            Self::ByteCount { expected, found } => {
+                write!(f, "Expected {expected} bytes, found {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for BinaryDeserError {}
+
+/// Swap the bytes of each element on big-endian targets, so elements are stored in little-endian order.
+fn to_little_endian<T>(bytes: &mut [u8]) {
+    if cfg!(target_endian = "big") {
+        bytes
+            .chunks_exact_mut(size_of::<T>())
+            .for_each(|e| e.reverse());
+    }
+}
+
+fn read_u32(b: &[u8], offset: usize) -> usize {
+    u32::from_le_bytes(b[offset..offset + 4].try_into().unwrap()) as usize
+}
+
+impl<'a, T: Pod, const LEN: usize> StaticCowVec<'a, T, LEN> {
+    /// Serialize the vector. See [`crate::bytes`] for the format.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_LEN + LEN * size_of::<T>());
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&(size_of::<T>() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(LEN as u32).to_le_bytes());
+        bytes.extend_from_slice(bytemuck::cast_slice(&self[..]));
+        to_little_endian::<T>(&mut bytes[HEADER_LEN..]);
+        bytes
+    }
+
+    /// Deserialize an owned vector, which has been serialized with [`StaticCowVec::to_bytes`].
+    /// The header is validated before the elements are read,
+    /// so bytes serialized from a vector with a different element size or length produce an error.
+    pub fn from_bytes(b: &[u8]) -> Result<Self, BinaryDeserError> {
+        if b.len() < HEADER_LEN || b[..8] != MAGIC {
+            return Err(BinaryDeserError::Magic);
+        }
+
+        let element_size = read_u32(b, 8);
+        if element_size != size_of::<T>() {
+            return Err(BinaryDeserError::ElementSize {
+                expected: size_of::<T>(),
+                found: element_size,
+            });
+        }
+
+        let len = read_u32(b, 12);
+        if len != LEN {
+            return Err(BinaryDeserError::Length {
+                expected: LEN,
+                found: len,
+            });
+        }
+
+        if b.len() != HEADER_LEN + LEN * size_of::<T>() {
+            return Err(BinaryDeserError::ByteCount {
+                expected: HEADER_LEN + LEN * size_of::<T>(),
+                found: b.len(),
+            });
+        }
+
+        let mut data = [T::zeroed(); LEN];
+        let data_bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut data[..]);
+        data_bytes.copy_from_slice(&b[HEADER_LEN..]);
+        to_little_endian::<T>(data_bytes);
+        Ok(Self::from(data))
+    }
+}
@@ -0,0 +1,109 @@
+//! Polar form and transcendental functions for complex numbers.
+//!
+//! [`Float`] and [`Complex`] are defined in [levitate](https://lib.rs/levitate),
+//! so the functions are provided by the extension traits [`TranscendentalFloat`] and [`ComplexExt`].
+//!
+//! ## Example
+//! ```rust
+//! use slas::prelude::*;
+//!
+//! let c = Complex::<f64>::from_polar(2., std::f64::consts::FRAC_PI_2);
+//! assert!(c.re.abs() < 1e-15);
+//! assert!((c.im - 2.).abs() < 1e-15);
+//!
+//! let (r, theta) = c.to_polar();
+//! assert!((r - 2.).abs() < 1e-15);
+//! assert!((theta - std::f64::consts::FRAC_PI_2).abs() < 1e-15);
+//!
+//! // Euler's formula
+//! let e = Complex::<f64>::cis(std::f64::consts::PI);
+//! assert!((e.re + 1.).abs() < 1e-15 && e.im.abs() < 1e-15);
+//! ```
+
+use crate::prelude::*;
+
+/// Transcendental functions on real floats, which are not part of [`Float`].
+pub trait TranscendentalFloat: Float {
+    /// Four quadrant arctangent of `self` (y) and `other` (x).
+    fn atan2_(self, other: Self) -> Self;
+    /// Natural logarithm.
+    fn ln_(self) -> Self;
+    /// Inverse cosine.
+    fn acos_(self) -> Self;
+}
+
+macro_rules! impl_transcendental_float {
+    ($($t: ty),*) => {$(
+        impl TranscendentalFloat for $t {
+            #[inline(always)]
+            fn atan2_(self, other: Self) -> Self {
+                self.atan2(other)
+            }
+            #[inline(always)]
+            fn ln_(self) -> Self {
+                self.ln()
+            }
+            #[inline(always)]
+            fn acos_(self) -> Self {
+                self.acos()
+            }
+        }
+    )*};
+}
+
+impl_transcendental_float!(f32, f64);
+
+/// Polar form and transcendental functions of complex numbers.
+pub trait ComplexExt<T> {
+    /// Absolute value `sqrt(re^2 + im^2)`.
+    fn modulus(&self) -> T;
+    /// Angle from the positive real axis, in the range `[-pi, pi]`.
+    fn phase(&self) -> T;
+    /// Returns `(modulus, phase)`.
+    fn to_polar(&self) -> (T, T);
+    /// Complex number with modulus `r` and phase `theta`.
+    fn from_polar(r: T, theta: T) -> Self;
+    /// `cos(theta) + i * sin(theta)`, the complex number on the unit circle with phase `theta`.
+    /// Useful for DFT twiddle factors.
+    fn cis(theta: T) -> Self;
+    /// Complex exponential `e^re * cis(im)`.
+    fn cexp(&self) -> Self;
+    /// Principal complex logarithm `ln(modulus) + i * phase`.
+    fn cln(&self) -> Self;
+}
+
+impl<T: TranscendentalFloat> ComplexExt<T> for Complex<T> {
+    fn modulus(&self) -> T {
+        (self.re * self.re + self.im * self.im).sqrt_()
+    }
+
+    fn phase(&self) -> T {
+        self.im.atan2_(self.re)
+    }
+
+    fn to_polar(&self) -> (T, T) {
+        (self.modulus(), self.phase())
+    }
+
+    fn from_polar(r: T, theta: T) -> Self {
+        Complex {
+            re: r * theta.cos_(),
+            im: r * theta.sin_(),
+        }
+    }
+
+    fn cis(theta: T) -> Self {
+        Self::from_polar(T::_1, theta)
+    }
+
+    fn cexp(&self) -> Self {
+        Self::from_polar(self.re.exp_(), self.im)
+    }
+
+    fn cln(&self) -> Self {
+        Complex {
+            re: self.modulus().ln_(),
+            im: self.phase(),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Reading and writing matricies as csv files.
+//! Only available with the `csv` feature.
+//!
+//! Elements are separated by commas, and each row is on its own line.
+//!
+//! ## Example
+//! ```rust
+//! use slas::prelude::*;
+//!
+//! let path = std::env::temp_dir().join("slas_csv_example.csv");
+//!
+//! let m = moo![f32: 1..7].matrix::<slas_backend::Rust, 2, 3>();
+//! m.to_csv(&path).unwrap();
+//! assert_eq!(std::fs::read_to_string(&path).unwrap(), "1,2,3\n4,5,6\n");
+//!
+//! let read = slas::csv::from_csv::<f32, 2, 3>(&path).unwrap();
+//! assert_eq!(*read.vec_ref(), [1., 2., 3., 4., 5., 6.]);
+//!
+//! assert!(slas::csv::from_csv::<f32, 3, 2>(&path).is_err());
+//! ```
+
+use crate::backends::Rust;
+use crate::prelude::*;
+use crate::tensor::Shape;
+use std::convert::TryInto;
+use std::io::Write;
+use std::path::Path;
+
+/// Error returned when reading or writing a csv file fails.
+#[derive(Debug)]
+pub enum CsvError {
+    Io(std::io::Error),
+    /// The file does not have the expected number of rows.
+    RowCount {
+        expected: usize,
+        found: usize,
+    },
+    /// A row does not have the expected number of columns.
+    ColumnCount {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// An element could not be parsed.
+    Parse {
+        row: usize,
+        column: usize,
+        value: String,
+    },
+}
+
+impl std::fmt::Display for CsvError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "{e}"),
+            Self::RowCount { expected, found } => {
+                write!(f, "Expected {expected} rows in csv file, found {found}")
+            }
+            Self::ColumnCount {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Expected {expected} columns in row {row} of csv file, found {found}"
+            ),
+            Self::Parse { row, column, value } => write!(
+                f,
+                "Cannot parse {value:?} at row {row}, column {column} of csv file"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CsvError {
+    fn from(e: std::io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+/// Read a `M`x`K` matrix from a csv file.
+/// Empty lines at the end of the file are ignored, and whitespace around elements is trimmed.
+pub fn from_csv<T: std::str::FromStr, const M: usize, const K: usize>(
+    path: impl AsRef<Path>,
+) -> Result<Matrix<T, [T; M * K], Rust, { M * K }, false, MatrixShape<M, K>>, CsvError>
+where
+    Rust: Backend<T>,
+    [(); M * K]:,
+{
+    let text = std::fs::read_to_string(path)?;
+    let lines: Vec<&str> = text.trim_end().lines().collect();
+    if lines.len() != M {
+        return Err(CsvError::RowCount {
+            expected: M,
+            found: lines.len(),
+        });
+    }
+
+    let mut data = Vec::with_capacity(M * K);
+    for (row, line) in lines.iter().enumerate() {
+        let before = data.len();
+        for (column, value) in line.split(',').enumerate() {
+            let value = value.trim();
+            data.push(value.parse().map_err(|_| CsvError::Parse {
+                row,
+                column,
+                value: value.to_string(),
+            })?);
+        }
+        if data.len() - before != K {
+            return Err(CsvError::ColumnCount {
+                row,
+                expected: K,
+                found: data.len() - before,
+            });
+        }
+    }
+
+    let data: [T; M * K] = match data.try_into() {
+        Ok(data) => data,
+        Err(_) => unreachable!(),
+    };
+    Ok(data.matrix::<Rust, M, K>())
+}
+
+impl<
+        T: std::fmt::Display,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        S: Shape<2>,
+        const LEN: usize,
+        const IS_TRANS: bool,
+    > Matrix<T, U, B, LEN, IS_TRANS, S>
+{
+    /// Write the matrix to a csv file. See [`crate::csv`].
+    pub fn to_csv(&self, path: impl AsRef<Path>) -> Result<(), CsvError> {
+        let mut f = std::io::BufWriter::new(std::fs::File::create(path)?);
+        for r in 0..self.rows() {
+            for c in 0..self.columns() {
+                if c != 0 {
+                    f.write_all(b",")?;
+                }
+                write!(f, "{}", self[(r, c)])?;
+            }
+            f.write_all(b"\n")?;
+        }
+        f.flush()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,996 @@
+//! Matrix decompositions and orthogonalization.
+
+use crate::backends::operations;
+use crate::prelude::*;
+
+/// Columns with a norm smaller than this (after removing projections onto previous columns)
+/// are considered linearly dependent during orthogonalization.
+pub const ORTHOGONALIZATION_EPSILON: f32 = 1e-6;
+
+/// Singular values smaller than this, relative to the largest singular value,
+/// are treated as zero by [`Matrix::pinv`](crate::tensor::Matrix::pinv).
+pub const PINV_TOLERANCE: f32 = 1e-6;
+
+/// Matricies with a pivot smaller than this (in absolute value) during LU decomposition are considered singular.
+/// This is used by [`Matrix::inv`](crate::tensor::Matrix::inv).
+pub const SINGULAR_EPSILON: f32 = 1e-6;
+
+/// Error returned when the columns of a matrix could not be made orthonormal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrthogonalizationError {
+    /// The column with the given index is (numerically) a linear combination of the columns before it.
+    LinearlyDependent(usize),
+    /// A matrix with more columns than rows cannot have orthonormal columns.
+    TooManyColumns,
+}
+
+impl std::fmt::Display for OrthogonalizationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LinearlyDependent(n) => write!(
+                f,
+                "Column {n} is linearly dependent on the columns before it"
+            ),
+            Self::TooManyColumns => write!(
+                f,
+                "Cannot orthogonalize a matrix with more columns than rows"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for OrthogonalizationError {}
+
+/// Modified Gram-Schmidt orthonormalization of the columns of a row-major `M`x`K` matrix, in place.
+pub(crate) fn orthonormalize_columns<
+    T: Float + PartialOrd + From<f32>,
+    const M: usize,
+    const K: usize,
+    const LEN: usize,
+>(
+    a: &mut [T; LEN],
+) -> Result<(), OrthogonalizationError> {
+    debug_assert_eq!(M * K, LEN);
+    if K > M {
+        return Err(OrthogonalizationError::TooManyColumns);
+    }
+    let eps = <T as From<f32>>::from(ORTHOGONALIZATION_EPSILON);
+
+    for j in 0..K {
+        let mut norm = T::_0;
+        for r in 0..M {
+            norm += a[r * K + j] * a[r * K + j];
+        }
+        let norm = norm.sqrt_();
+        if norm.partial_cmp(&eps) != Some(std::cmp::Ordering::Greater) {
+            return Err(OrthogonalizationError::LinearlyDependent(j));
+        }
+        for r in 0..M {
+            a[r * K + j] /= norm;
+        }
+
+        for k in j + 1..K {
+            let mut dot = T::_0;
+            for r in 0..M {
+                dot += a[r * K + j] * a[r * K + k];
+            }
+            for r in 0..M {
+                a[r * K + k] -= dot * a[r * K + j];
+            }
+        }
+    }
+    Ok(())
+}
+
+impl<
+        T: Float + PartialOrd + From<f32>,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    > Matrix<T, U, B, LEN, false, MatrixShape<M, K>>
+{
+    /// Returns a matrix with orthonormal columns spanning the same space as the columns of `self`,
+    /// using the modified Gram-Schmidt process.
+    ///
+    /// # Panics
+    /// Will panic if the columns of `self` are linearly dependent.
+    /// Use [`OrthogonalMatrix::new`] if you want to handle this case.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let q = moo![f32: 1, 1, 0, 1].matrix::<slas_backend::Rust, 2, 2>().gram_schmidt();
+    /// assert_eq!(*q.vec_ref(), [1., 0., 0., 1.]);
+    /// ```
+    pub fn gram_schmidt(&self) -> Matrix<T, [T; LEN], B, LEN, false, MatrixShape<M, K>> {
+        let mut data = [T::_0; LEN];
+        for r in 0..M {
+            for c in 0..K {
+                data[r * K + c] = self[(r, c)];
+            }
+        }
+        if let Err(e) = orthonormalize_columns::<T, M, K, LEN>(&mut data) {
+            panic!("Matrix::gram_schmidt failed: {}", e)
+        }
+        data.matrix::<B, M, K>()
+    }
+}
+
+/// A matrix with orthonormal columns.
+///
+/// The orthogonality invariant could be broken by mutating the inner data,
+/// which is why `OrthogonalMatrix` only dereferences immutably.
+/// Use [`OrthogonalMatrix::into_matrix`] if you need mutable access.
+///
+/// ## Example
+/// ```rust
+/// #![allow(incomplete_features)]
+/// #![feature(generic_const_exprs)]
+/// use slas::prelude::*;
+/// use slas::decompose::OrthogonalMatrix;
+///
+/// let a = moo![f32: 1, 1, 0, 1].matrix::<slas_backend::Rust, 2, 2>();
+/// let q = OrthogonalMatrix::<f32, _, _, 2, 2>::new(&a).unwrap();
+///
+/// assert_eq!(q[(0, 0)], 1.);
+/// assert_eq!(q[(1, 0)], 0.);
+/// assert_eq!(q[(0, 1)], 0.);
+/// assert_eq!(q[(1, 1)], 1.);
+/// ```
+#[derive(Clone, Copy)]
+pub struct OrthogonalMatrix<
+    T,
+    U: StaticVec<T, { M * K }>,
+    B: Backend<T>,
+    const M: usize,
+    const K: usize,
+>(Matrix<T, U, B, { M * K }, false, MatrixShape<M, K>>)
+where
+    [(); M * K]:;
+
+impl<T: Float + PartialOrd + From<f32>, B: Backend<T>, const M: usize, const K: usize>
+    OrthogonalMatrix<T, [T; M * K], B, M, K>
+where
+    [(); M * K]:,
+{
+    /// Orthonormalize the columns of `a` with the Gram-Schmidt process.
+    /// Returns an error if the columns of `a` are linearly dependent.
+    pub fn new<U: StaticVec<T, LEN>, const LEN: usize>(
+        a: &Matrix<T, U, B, LEN, false, MatrixShape<M, K>>,
+    ) -> Result<Self, OrthogonalizationError> {
+        let mut data = [T::_0; M * K];
+        for r in 0..M {
+            for c in 0..K {
+                data[r * K + c] = a[(r, c)];
+            }
+        }
+        orthonormalize_columns::<T, M, K, { M * K }>(&mut data)?;
+        Ok(Self(data.matrix::<B, M, K>()))
+    }
+}
+
+impl<T, U: StaticVec<T, { M * K }>, B: Backend<T>, const M: usize, const K: usize>
+    OrthogonalMatrix<T, U, B, M, K>
+where
+    [(); M * K]:,
+{
+    /// Returns a reference to the inner matrix.
+    pub const fn as_matrix(&self) -> &Matrix<T, U, B, { M * K }, false, MatrixShape<M, K>> {
+        &self.0
+    }
+
+    /// Returns the inner matrix, without the orthogonality guarantee.
+    pub fn into_matrix(self) -> Matrix<T, U, B, { M * K }, false, MatrixShape<M, K>> {
+        self.0
+    }
+}
+
+impl<T, U: StaticVec<T, { M * K }>, B: Backend<T>, const M: usize, const K: usize> std::ops::Deref
+    for OrthogonalMatrix<T, U, B, M, K>
+where
+    [(); M * K]:,
+{
+    type Target = Matrix<T, U, B, { M * K }, false, MatrixShape<M, K>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Returns the Householder vector `v` of `x`,
+/// such that the reflection `H = I - 2vvᵀ/‖v‖²` maps `x` onto a multiple of the first unit vector.
+///
+/// ## Example
+/// ```rust
+/// use slas::decompose::householder_vector;
+///
+/// assert_eq!(householder_vector(&[3f32, 4.]), [8., 4.]);
+/// ```
+pub fn householder_vector<T: Float + PartialOrd, const N: usize>(
+    x: &impl StaticVec<T, N>,
+) -> [T; N] {
+    let mut v = **x.moo_ref();
+    if N == 0 {
+        return v;
+    }
+
+    let mut norm = T::_0;
+    for x in &v {
+        norm += *x * *x;
+    }
+    let norm = norm.sqrt_();
+
+    // Adding the norm with the same sign as the first element, avoids cancellation.
+    v[0] = if v[0] < T::_0 {
+        v[0] - norm
+    } else {
+        v[0] + norm
+    };
+    v
+}
+
+/// Apply the Householder reflection defined by `v` (see [`householder_vector`]) to `a` from the left, in place.
+/// The reflection matrix is never materialized, instead `a` is updated with `a - (2/vᵀv) v (vᵀa)`.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::decompose::*;
+///
+/// let mut a = moo![f32: 3, 1, 4, 2].matrix::<slas_backend::Rust, 2, 2>();
+/// let v = householder_vector(&[3., 4.]);
+/// apply_householder(&v, &mut a);
+///
+/// assert_eq!(a[(0, 0)], -5.);
+/// assert_eq!(a[(1, 0)], 0.);
+/// ```
+pub fn apply_householder<
+    T: Float + PartialEq,
+    U: StaticVec<T, LEN>,
+    B: Backend<T> + operations::Axpy<T>,
+    const LEN: usize,
+    const M: usize,
+    const K: usize,
+>(
+    v: &impl StaticVec<T, M>,
+    a: &mut Matrix<T, U, B, LEN, false, MatrixShape<M, K>>,
+) {
+    assert_eq!(M * K, LEN);
+    let v = v.moo_ref();
+
+    let mut vv = T::_0;
+    for r in 0..M {
+        vv += v[r] * v[r];
+    }
+    if vv == T::_0 {
+        return;
+    }
+
+    let a = &mut a.0.data;
+
+    // w = vᵀa
+    let mut w = [T::_0; K];
+    for r in 0..M {
+        let row = unsafe { a.data.static_slice_unchecked::<K>(r * K) };
+        operations::Axpy::axpy(&a.backend, v[r], row, &mut w);
+    }
+
+    for r in 0..M {
+        let row = unsafe { a.data.mut_static_slice_unchecked::<K>(r * K) };
+        operations::Axpy::axpy(&a.backend, -(T::_2 * v[r] / vv), &w, row);
+    }
+}
+
+/// Returns `(cos, sin)` of the Givens rotation `G = [[c, s], [-s, c]]`, that maps `[a, b]` onto `[r, 0]`.
+///
+/// ## Example
+/// ```rust
+/// use slas::decompose::givens_rotation;
+///
+/// assert_eq!(givens_rotation(3f32, 4.), (0.6, 0.8));
+/// ```
+pub fn givens_rotation<T: Float + PartialEq>(a: T, b: T) -> (T, T) {
+    if b == T::_0 {
+        return (T::_1, T::_0);
+    }
+    let r = (a * a + b * b).sqrt_();
+    (a / r, b / r)
+}
+
+/// Rotate row `i` and `j` of `a` with the Givens rotation `(c, s)`, in place.
+/// This is the same as multiplying `a` from the left with the rotation matrix.
+///
+/// If `(c, s)` is `givens_rotation(a[(i, k)], a[(j, k)])`, then `a[(j, k)]` will be zero after the rotation.
+pub fn apply_givens_left<
+    T: Float,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>,
+    const LEN: usize,
+    const M: usize,
+    const K: usize,
+>(
+    c: T,
+    s: T,
+    i: usize,
+    j: usize,
+    a: &mut Matrix<T, U, B, LEN, false, MatrixShape<M, K>>,
+) {
+    for k in 0..K {
+        let ai = a[(i, k)];
+        let aj = a[(j, k)];
+        a[(i, k)] = c * ai + s * aj;
+        a[(j, k)] = c * aj - s * ai;
+    }
+}
+
+/// Rotate column `i` and `j` of `a` with the Givens rotation `(c, s)`, in place.
+/// This is the same as multiplying `a` from the right with the transposed rotation matrix.
+///
+/// If `(c, s)` is `givens_rotation(a[(k, i)], a[(k, j)])`, then `a[(k, j)]` will be zero after the rotation.
+pub fn apply_givens_right<
+    T: Float,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>,
+    const LEN: usize,
+    const M: usize,
+    const K: usize,
+>(
+    c: T,
+    s: T,
+    i: usize,
+    j: usize,
+    a: &mut Matrix<T, U, B, LEN, false, MatrixShape<M, K>>,
+) {
+    for k in 0..M {
+        let ai = a[(k, i)];
+        let aj = a[(k, j)];
+        a[(k, i)] = c * ai + s * aj;
+        a[(k, j)] = c * aj - s * ai;
+    }
+}
+
+pub(crate) fn abs<T: Float + PartialOrd>(x: T) -> T {
+    if x < T::_0 {
+        T::_0 - x
+    } else {
+        x
+    }
+}
+
+/// A permutation of `N` elements.
+///
+/// Element `n` of a permuted vector is element `P[n]` of the original vector,
+/// which matches the permutation vector returned by [`lu`].
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::decompose::Perm;
+///
+/// let p = Perm::new([2, 0, 1]).unwrap();
+/// assert_eq!(p.apply(&[1., 2., 3.]), [3., 1., 2.]);
+/// assert_eq!(p.apply_inv(&p.apply(&[1., 2., 3.])), [1., 2., 3.]);
+/// assert!(!p.is_odd());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Perm<const N: usize>([usize; N]);
+
+impl<const N: usize> Perm<N> {
+    /// Returns `None` if `p` is not a permutation of `0..N`.
+    pub fn new(p: [usize; N]) -> Option<Self> {
+        let mut seen = [false; N];
+        for &n in p.iter() {
+            if n >= N || seen[n] {
+                return None;
+            }
+            seen[n] = true;
+        }
+        Some(Self(p))
+    }
+
+    /// The permutation that leaves all elements in place.
+    pub fn identity() -> Self {
+        Self(std::array::from_fn(|n| n))
+    }
+
+    pub fn as_array(&self) -> &[usize; N] {
+        &self.0
+    }
+
+    /// Returns true if the permutation consists of an odd number of swaps.
+    /// The determinant of the corresponding permutation matrix is -1 if this is true, and 1 otherwise.
+    pub fn is_odd(&self) -> bool {
+        let mut visited = [false; N];
+        let mut odd = false;
+        for start in 0..N {
+            let mut n = start;
+            while !visited[n] {
+                visited[n] = true;
+                n = self.0[n];
+                if n != start {
+                    odd = !odd;
+                }
+            }
+        }
+        odd
+    }
+
+    /// Returns `v` permuted, such that element `n` of the output is element `self[n]` of `v`.
+    pub fn apply<T: Copy, const LEN: usize>(&self, v: &impl StaticVec<T, LEN>) -> [T; LEN] {
+        assert_eq!(
+            N, LEN,
+            "Cannot apply permutation of {N} elements to vector of {LEN}"
+        );
+        let v = v.moo_ref();
+        let mut out = **v;
+        for n in 0..N {
+            out[n] = v[self.0[n]];
+        }
+        out
+    }
+
+    /// Reverses [`Perm::apply`].
+    pub fn apply_inv<T: Copy, const LEN: usize>(&self, v: &impl StaticVec<T, LEN>) -> [T; LEN] {
+        assert_eq!(
+            N, LEN,
+            "Cannot apply permutation of {N} elements to vector of {LEN}"
+        );
+        let v = v.moo_ref();
+        let mut out = **v;
+        for n in 0..N {
+            out[self.0[n]] = v[n];
+        }
+        out
+    }
+
+    /// Permute the rows of `a` in place, such that row `n` becomes row `self[n]` of the original matrix.
+    pub fn apply_left<
+        T: Copy,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    >(
+        &self,
+        a: &mut Matrix<T, U, B, LEN, false, MatrixShape<M, K>>,
+    ) {
+        assert_eq!(
+            N, M,
+            "Cannot apply permutation of {N} elements to matrix with {M} rows"
+        );
+        let original = **a.vec_ref().moo_ref();
+        for r in 0..M {
+            for c in 0..K {
+                a[(r, c)] = original[self.0[r] * K + c];
+            }
+        }
+    }
+}
+
+impl<const N: usize> std::ops::Index<usize> for Perm<N> {
+    type Output = usize;
+
+    fn index(&self, n: usize) -> &usize {
+        &self.0[n]
+    }
+}
+
+/// LU decomposition with partial pivoting, using the Doolittle algorithm.
+///
+/// Returns `(L, U, P)` where `L` is lower triangular with ones on the diagonal,
+/// `U` is upper triangular and `P` is a permutation vector, such that row `n` of `L * U` is row `P[n]` of `a`.
+/// `L` and `U` are stored row-major, and can be used as matricies with [`StaticVec::matrix`].
+///
+/// This is implemented in pure rust, and does not depend on blas.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::decompose::lu;
+///
+/// let (l, u, p) = lu(&moo![f32: 1, 2, 3, 4].matrix::<slas_backend::Rust, 2, 2>());
+///
+/// assert_eq!(l, [1., 0., 1. / 3., 1.]);
+/// assert_eq!(u, [3., 4., 0., 2. - 4. / 3.]);
+/// assert_eq!(p, [1, 0]);
+/// ```
+pub fn lu<
+    T: Float + PartialOrd,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>,
+    const N: usize,
+    const LEN: usize,
+>(
+    a: &Matrix<T, U, B, LEN, false, MatrixShape<N, N>>,
+) -> ([T; LEN], [T; LEN], [usize; N]) {
+    let (l, u, p, _) = lu_decompose::<T, N, LEN>(**a.vec_ref().moo_ref());
+    (l, u, p)
+}
+
+/// [`lu`] on a row-major `N`x`N` matrix stored as an array.
+/// The last element of the returned tuple is true if an odd number of row swaps were performed.
+pub(crate) fn lu_decompose<T: Float + PartialOrd, const N: usize, const LEN: usize>(
+    mut u: [T; LEN],
+) -> ([T; LEN], [T; LEN], [usize; N], bool) {
+    debug_assert_eq!(N * N, LEN);
+    let mut l = [T::_0; LEN];
+    let mut p: [usize; N] = std::array::from_fn(|n| n);
+    let mut odd = false;
+
+    for k in 0..N {
+        let mut pivot = k;
+        for r in k + 1..N {
+            if abs(u[r * N + k]) > abs(u[pivot * N + k]) {
+                pivot = r;
+            }
+        }
+        if pivot != k {
+            for c in 0..N {
+                u.swap(k * N + c, pivot * N + c);
+            }
+            for c in 0..k {
+                l.swap(k * N + c, pivot * N + c);
+            }
+            p.swap(k, pivot);
+            odd = !odd;
+        }
+
+        if u[k * N + k] == T::_0 {
+            continue;
+        }
+        for r in k + 1..N {
+            let f = u[r * N + k] / u[k * N + k];
+            l[r * N + k] = f;
+            for c in k..N {
+                u[r * N + c] -= f * u[k * N + c];
+            }
+        }
+    }
+
+    for n in 0..N {
+        l[n * N + n] = T::_1;
+    }
+    (l, u, p, odd)
+}
+
+impl<
+        T: Float + PartialOrd,
+        U: StaticVec<T, LEN>,
+        B: Backend<T> + crate::backends::operations::TriangularSolve<T>,
+        const N: usize,
+        const LEN: usize,
+    > Matrix<T, U, B, LEN, false, MatrixShape<N, N>>
+{
+    /// Solve `self * x = b` for `x`, using [`lu`] decomposition.
+    /// The triangular systems are solved with the backend of `self`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 2, 0, 0, 4].matrix::<slas_backend::Rust, 2, 2>();
+    /// assert_eq!(a.solve(&[1., 1.]), [0.5, 0.25]);
+    /// ```
+    pub fn solve(&self, b: &impl StaticVec<T, N>) -> [T; N] {
+        let (l, u, p) = lu(self);
+        let b = b.moo_ref();
+
+        let mut x = [T::_0; N];
+        for n in 0..N {
+            x[n] = b[p[n]];
+        }
+
+        let backend = self.backend();
+        operations::TriangularSolve::triangular_solve(backend, &l, &mut x, false, true);
+        operations::TriangularSolve::triangular_solve(backend, &u, &mut x, true, false);
+        x
+    }
+}
+
+impl<
+        T: Float + PartialOrd,
+        U: StaticVec<T, LEN>,
+        B: Backend<T> + operations::Inverse<T, InverseOutput = bool>,
+        const N: usize,
+        const LEN: usize,
+    > Matrix<T, U, B, LEN, false, MatrixShape<N, N>>
+{
+    /// Returns the inverse of `self`, or `None` if `self` is singular.
+    /// A matrix is considered singular if a pivot in its LU decomposition is smaller than [`SINGULAR_EPSILON`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 2, 0, 0, 4].matrix::<slas_backend::Rust, 2, 2>();
+    /// assert_eq!(a.inv(), Some([0.5, 0., 0., 0.25]));
+    ///
+    /// let a = moo![f32: 1, 2, 2, 4].matrix::<slas_backend::Rust, 2, 2>();
+    /// assert_eq!(a.inv(), None);
+    /// ```
+    pub fn inv(&self) -> Option<[T; LEN]>
+    where
+        T: From<f32>,
+    {
+        self.inv_with_epsilon(<T as From<f32>>::from(SINGULAR_EPSILON))
+    }
+
+    /// Same as [`Matrix::inv`], but with a custom threshold for when a matrix is considered singular.
+    pub fn inv_with_epsilon(&self, epsilon: T) -> Option<[T; LEN]> {
+        let mut buffer = [T::_0; LEN];
+        if operations::Inverse::inverse::<N, LEN>(
+            self.backend(),
+            self.vec_ref(),
+            &mut buffer,
+            epsilon,
+        ) {
+            Some(buffer)
+        } else {
+            None
+        }
+    }
+}
+
+impl<
+        T: Float + PartialOrd,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const N: usize,
+        const LEN: usize,
+    > Matrix<T, U, B, LEN, false, MatrixShape<N, N>>
+{
+    /// Determinant of a square matrix, computed from its [`lu`] decomposition.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 3, 8, 4, 6].matrix::<slas_backend::Rust, 2, 2>();
+    /// assert_eq!(a.det(), -14.);
+    /// ```
+    pub fn det(&self) -> T {
+        let (_, u, _, odd) = lu_decompose::<T, N, LEN>(**self.vec_ref().moo_ref());
+        let mut det = if odd { T::_0 - T::_1 } else { T::_1 };
+        for n in 0..N {
+            det *= u[n * N + n];
+        }
+        det
+    }
+}
+
+impl<
+        T: Float + PartialOrd,
+        U: StaticVec<T, LEN>,
+        B: Backend<T> + operations::Axpy<T>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    > Matrix<T, U, B, LEN, false, MatrixShape<M, K>>
+where
+    [(); M * M]:,
+{
+    /// QR decomposition using Householder reflections.
+    ///
+    /// Returns `(Q, R)` where `Q` is an orthogonal `M`x`M` matrix and `R` is an upper triangular `M`x`K` matrix,
+    /// such that `Q * R = self`. Both are stored row-major.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let (q, r) = moo![f32: 3, 1, 4, 2].matrix::<slas_backend::Rust, 2, 2>().qr();
+    ///
+    /// assert_eq!(r[2], 0.);
+    /// assert!((r[0] + 5.).abs() < 1e-6);
+    /// assert!((q[0] + 0.6).abs() < 1e-6);
+    /// ```
+    pub fn qr(&self) -> ([T; M * M], [T; LEN]) {
+        let mut r = [T::_0; LEN];
+        for row in 0..M {
+            for col in 0..K {
+                r[row * K + col] = self[(row, col)];
+            }
+        }
+        let mut r = r.matrix::<B, M, K>();
+
+        let mut q_t = [T::_0; M * M];
+        for n in 0..M {
+            q_t[n * M + n] = T::_1;
+        }
+        let mut q_t = q_t.matrix::<B, M, M>();
+
+        // The last reflection of a square matrix would only flip the sign of the last row.
+        for j in 0..K.min(M.saturating_sub(1)) {
+            let mut v = [T::_0; M];
+            let mut norm = T::_0;
+            for row in j..M {
+                v[row] = r[(row, j)];
+                norm += v[row] * v[row];
+            }
+            let norm = norm.sqrt_();
+            v[j] = if v[j] < T::_0 {
+                v[j] - norm
+            } else {
+                v[j] + norm
+            };
+
+            apply_householder(&v, &mut r);
+            apply_householder(&v, &mut q_t);
+        }
+
+        let mut q = [T::_0; M * M];
+        for row in 0..M {
+            for col in 0..M {
+                q[row * M + col] = q_t[(col, row)];
+            }
+        }
+
+        let mut r = *r.vec_ref();
+        for row in 0..M {
+            for col in 0..row.min(K) {
+                r[row * K + col] = T::_0;
+            }
+        }
+        (q, r)
+    }
+
+    /// Same as [`Matrix::qr`], but uses LAPACK's `geqrf` and `orgqr`.
+    #[cfg(feature = "lapack")]
+    pub fn qr_blas(&self) -> ([T; M * M], [T; LEN])
+    where
+        T: crate::lapack::Lapack,
+    {
+        // LAPACK expects column-major matricies.
+        let mut a = [T::_0; LEN];
+        for row in 0..M {
+            for col in 0..K {
+                a[col * M + row] = self[(row, col)];
+            }
+        }
+
+        let reflectors = K.min(M);
+        let mut tau = [T::_0; M];
+        let info = unsafe { T::geqrf(M, K, a.as_mut_ptr(), M, tau.as_mut_ptr()) };
+        assert_eq!(info, 0, "geqrf failed with info {info}");
+
+        let mut r = [T::_0; LEN];
+        let mut q = [T::_0; M * M];
+        for row in 0..M {
+            for col in 0..K {
+                if row <= col {
+                    r[row * K + col] = a[col * M + row];
+                } else if col < reflectors {
+                    q[col * M + row] = a[col * M + row];
+                }
+            }
+        }
+
+        let info = unsafe { T::orgqr(M, M, reflectors, q.as_mut_ptr(), M, tau.as_ptr()) };
+        assert_eq!(info, 0, "orgqr failed with info {info}");
+
+        let mut q_t = q;
+        for row in 0..M {
+            for col in 0..M {
+                q_t[row * M + col] = q[col * M + row];
+            }
+        }
+        (q_t, r)
+    }
+}
+
+/// Same as [`lu`], but uses LAPACK's `sgetrf`/`dgetrf`.
+#[cfg(feature = "lapack")]
+pub fn lu_blas<
+    T: crate::lapack::Lapack + PartialOrd,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>,
+    const N: usize,
+    const LEN: usize,
+>(
+    a: &Matrix<T, U, B, LEN, false, MatrixShape<N, N>>,
+) -> ([T; LEN], [T; LEN], [usize; N]) {
+    // LAPACK expects column-major matricies.
+    let mut buffer = [T::_0; LEN];
+    for r in 0..N {
+        for c in 0..N {
+            buffer[c * N + r] = a[(r, c)];
+        }
+    }
+
+    let mut ipiv = [0i32; N];
+    let info = unsafe { T::getrf(N, N, buffer.as_mut_ptr(), N, ipiv.as_mut_ptr()) };
+    assert!(info >= 0, "Illegal argument {} passed to getrf", -info);
+
+    let mut p: [usize; N] = std::array::from_fn(|n| n);
+    for (n, &i) in ipiv.iter().enumerate() {
+        p.swap(n, i as usize - 1);
+    }
+
+    let mut l = [T::_0; LEN];
+    let mut u = [T::_0; LEN];
+    for r in 0..N {
+        for c in 0..N {
+            let v = buffer[c * N + r];
+            if r > c {
+                l[r * N + c] = v;
+            } else {
+                u[r * N + c] = v;
+            }
+        }
+        l[r * N + r] = T::_1;
+    }
+    (l, u, p)
+}
+
+/// Smallest of two dimensions, fx. the number of singular values of a `M`x`K` matrix.
+pub const fn min_dim(m: usize, k: usize) -> usize {
+    if m < k {
+        m
+    } else {
+        k
+    }
+}
+
+/// Singular value decomposition of a `M`x`K` matrix, such that `A = U * diag(S) * V^T`.
+/// All matricies are stored row-major.
+#[derive(Clone, Copy, Debug)]
+pub struct SvdResult<T, const M: usize, const K: usize>
+where
+    [(); M * M]:,
+    [(); min_dim(M, K)]:,
+    [(); K * K]:,
+{
+    /// Orthogonal `M`x`M` matrix of left singular vectors (one per column).
+    pub u: [T; M * M],
+    /// Singular values in descending order.
+    pub s: [T; min_dim(M, K)],
+    /// Orthogonal `K`x`K` matrix of right singular vectors (one per row).
+    pub vt: [T; K * K],
+}
+
+/// Singular value decomposition using LAPACK's `sgesvd`/`dgesvd`.
+///
+/// ## Example
+/// ```rust
+/// use slas::{decompose::svd, prelude::*};
+///
+/// let a = moo![f64: 3, 0, 0, -2].matrix::<slas_backend::Blas, 2, 2>();
+/// let s = svd(&a).s;
+///
+/// assert!((s[0] - 3.).abs() < 1e-10);
+/// assert!((s[1] - 2.).abs() < 1e-10);
+/// ```
+#[cfg(feature = "lapack")]
+pub fn svd<
+    T: crate::lapack::Lapack,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>,
+    const M: usize,
+    const K: usize,
+    const LEN: usize,
+>(
+    a: &Matrix<T, U, B, LEN, false, MatrixShape<M, K>>,
+) -> SvdResult<T, M, K>
+where
+    [(); M * M]:,
+    [(); min_dim(M, K)]:,
+    [(); K * K]:,
+{
+    assert_eq!(M * K, LEN);
+
+    // LAPACK expects column-major matricies.
+    let mut buffer = [T::_0; LEN];
+    for r in 0..M {
+        for c in 0..K {
+            buffer[c * M + r] = a[(r, c)];
+        }
+    }
+
+    let mut s = [T::_0; min_dim(M, K)];
+    let mut u = [T::_0; M * M];
+    let mut vt = [T::_0; K * K];
+    let mut superb = vec![T::_0; min_dim(M, K).max(1)];
+    let info = unsafe {
+        T::gesvd(
+            M,
+            K,
+            buffer.as_mut_ptr(),
+            M.max(1),
+            s.as_mut_ptr(),
+            u.as_mut_ptr(),
+            M.max(1),
+            vt.as_mut_ptr(),
+            K.max(1),
+            superb.as_mut_ptr(),
+        )
+    };
+    assert!(info >= 0, "Illegal argument {} passed to gesvd", -info);
+    assert_eq!(info, 0, "gesvd did not converge ({info} superdiagonals)");
+
+    // Transposing column-major U and V^T gives them in row-major order.
+    let mut u_t = u;
+    for r in 0..M {
+        for c in 0..M {
+            u_t[r * M + c] = u[c * M + r];
+        }
+    }
+    let mut vt_t = vt;
+    for r in 0..K {
+        for c in 0..K {
+            vt_t[r * K + c] = vt[c * K + r];
+        }
+    }
+
+    SvdResult {
+        u: u_t,
+        s,
+        vt: vt_t,
+    }
+}
+
+#[cfg(feature = "lapack")]
+impl<
+        T: crate::lapack::Lapack + PartialOrd,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    > Matrix<T, U, B, LEN, false, MatrixShape<M, K>>
+where
+    [(); M * M]:,
+    [(); min_dim(M, K)]:,
+    [(); K * K]:,
+    [(); K * M]:,
+{
+    /// Moore-Penrose pseudoinverse, computed from the [`svd`] of `self`.
+    /// Singular values smaller than [`PINV_TOLERANCE`] times the largest singular value are treated as zero.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f64: 1, 0, 0, 2, 0, 0].matrix::<slas_backend::Blas, 3, 2>();
+    /// let p = a.pinv();
+    ///
+    /// let expected = [1., 0., 0., 0., 0.5, 0.];
+    /// assert!(p.vec_ref().iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-10));
+    /// ```
+    pub fn pinv(&self) -> Matrix<T, [T; K * M], B, { K * M }, false, MatrixShape<K, M>>
+    where
+        T: From<f32>,
+    {
+        self.pinv_with_tolerance(<T as From<f32>>::from(PINV_TOLERANCE))
+    }
+
+    /// Same as [`Matrix::pinv`], but with a custom relative threshold for small singular values.
+    pub fn pinv_with_tolerance(
+        &self,
+        tol: T,
+    ) -> Matrix<T, [T; K * M], B, { K * M }, false, MatrixShape<K, M>> {
+        let SvdResult { u, s, vt } = svd(self);
+
+        let cutoff = if min_dim(M, K) > 0 { tol * s[0] } else { T::_0 };
+        let mut s_inv = [T::_0; min_dim(M, K)];
+        for n in 0..min_dim(M, K) {
+            if s[n] > cutoff {
+                s_inv[n] = T::_1 / s[n];
+            }
+        }
+
+        // pinv(A) = V * diag(S)^+ * U^T
+        let mut p = [T::_0; K * M];
+        for r in 0..K {
+            for c in 0..M {
+                for n in 0..min_dim(M, K) {
+                    p[r * M + c] += vt[n * K + r] * s_inv[n] * u[c * M + n];
+                }
+            }
+        }
+        p.matrix::<B, K, M>()
+    }
+}
@@ -0,0 +1,72 @@
+//! Similarity and distance measures between vectors.
+
+use crate::prelude::*;
+
+/// Floats with a default backend for dot products,
+/// selected the same way as for [`StaticVecUnion::dot`].
+pub trait DefaultDotProduct: Float {
+    fn default_dot<const LEN: usize>(
+        a: &impl StaticVec<Self, LEN>,
+        b: &impl StaticVec<Self, LEN>,
+    ) -> Self;
+}
+
+macro_rules! impl_default_dot {
+    ($($t: ty),*) => {$(
+        impl DefaultDotProduct for $t {
+            fn default_dot<const LEN: usize>(
+                a: &impl StaticVec<Self, LEN>,
+                b: &impl StaticVec<Self, LEN>,
+            ) -> Self {
+                a.moo_ref().dot(b.moo_ref())
+            }
+        }
+    )*};
+}
+
+impl_default_dot!(f32, f64);
+
+/// Cosine of the angle between `a` and `b`.
+/// The result is clamped to `[-1, 1]`, and is 0 if either of the vectors only contains zeros.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// assert_eq!(cosine_similarity(&[1., 0.], &[0., 2.]), 0.);
+/// assert_eq!(cosine_similarity(&[1., 1.], &[-2., -2.]), -1.);
+/// ```
+pub fn cosine_similarity<T: DefaultDotProduct + PartialOrd, const LEN: usize>(
+    a: &impl StaticVec<T, LEN>,
+    b: &impl StaticVec<T, LEN>,
+) -> T {
+    let norms = (T::default_dot(a, a) * T::default_dot(b, b)).sqrt_();
+    if norms == T::_0 {
+        return T::_0;
+    }
+
+    let similarity = T::default_dot(a, b) / norms;
+    if similarity > T::_1 {
+        T::_1
+    } else if similarity < T::_0 - T::_1 {
+        T::_0 - T::_1
+    } else {
+        similarity
+    }
+}
+
+/// Euclidean length of `a - b`.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// assert_eq!(euclidean_distance(&[1., 2.], &[4., 6.]), 5.);
+/// ```
+pub fn euclidean_distance<T: DefaultDotProduct, const LEN: usize>(
+    a: &impl StaticVec<T, LEN>,
+    b: &impl StaticVec<T, LEN>,
+) -> T {
+    let d: [T; LEN] = std::array::from_fn(|n| unsafe { *a.get_unchecked(n) - *b.get_unchecked(n) });
+    T::default_dot(&d, &d).sqrt_()
+}
@@ -0,0 +1,244 @@
+use crate::backends::{operations, BackendOp, Rust};
+use crate::prelude::*;
+use std::ops::*;
+use std::simd::Simd;
+
+/// Dual number for forward-mode automatic differentiation.
+/// `re` is the real part (the value) and `du` is the dual part (the derivative).
+///
+/// The derivative of a function, with respect to some input,
+/// can be found by setting `du` of that input to one, and evaluating the function.
+/// The dual part of the output will then be the derivative.
+///
+/// Dual numbers are `repr(C)` so they can be used as elements in any [`StaticVec`].
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let f = |x: StaticCowVec<Dual<f32>, 3>| x.dot(&x);
+///
+/// // Differentiate with respect to x[1].
+/// let x = StaticCowVec::from([Dual::constant(1.), Dual::variable(2.), Dual::constant(3.)]);
+/// let y = f(x);
+///
+/// assert_eq!(y.re, 14.);
+/// assert_eq!(y.du, 4.);
+/// ```
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Dual<T: Float> {
+    pub re: T,
+    pub du: T,
+}
+
+impl<T: Float> Dual<T> {
+    pub const fn new(re: T, du: T) -> Self {
+        Self { re, du }
+    }
+
+    /// Dual number with a dual part of zero.
+    pub const fn constant(re: T) -> Self {
+        Self { re, du: T::_0 }
+    }
+
+    /// Dual number with a dual part of one.
+    /// This is the value you want to differentiate with respect to.
+    pub const fn variable(re: T) -> Self {
+        Self { re, du: T::_1 }
+    }
+}
+
+impl<T: Float> From<T> for Dual<T> {
+    fn from(re: T) -> Self {
+        Self::constant(re)
+    }
+}
+
+impl<T: Float> Add for Dual<T> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(self.re + other.re, self.du + other.du)
+    }
+}
+
+impl<T: Float> Sub for Dual<T> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.re - other.re, self.du - other.du)
+    }
+}
+
+impl<T: Float> Mul for Dual<T> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.re * other.re, self.re * other.du + self.du * other.re)
+    }
+}
+
+impl<T: Float> Div for Dual<T> {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        Self::new(
+            self.re / other.re,
+            (self.du * other.re - self.re * other.du) / (other.re * other.re),
+        )
+    }
+}
+
+impl<T: Float> Neg for Dual<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(T::_0 - self.re, T::_0 - self.du)
+    }
+}
+
+macro_rules! impl_assign_op {
+    ($($op: ident $fn: ident $assign: ident $assign_fn: ident),*) => {$(
+        impl<T: Float> $assign for Dual<T> {
+            fn $assign_fn(&mut self, other: Self) {
+                *self = self.$fn(other)
+            }
+        }
+    )*};
+}
+
+impl_assign_op!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign
+);
+
+impl<T: Float> std::iter::Sum for Dual<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::_0, |a, b| a + b)
+    }
+}
+
+impl<T: Float> FloatWrapper for Dual<T> {
+    type InnerFloat = T::InnerFloat;
+
+    fn from_primitive(f: Self::InnerFloat) -> Self {
+        Self::constant(T::from_primitive(f))
+    }
+
+    fn into_primitive(self) -> Self::InnerFloat {
+        self.re.into_primitive()
+    }
+
+    fn from_f64(f: f64) -> Self {
+        Self::constant(T::from_f64(f))
+    }
+}
+
+impl<T: Float> Float for Dual<T> {
+    const _0: Self = Self::new(T::_0, T::_0);
+    const _1: Self = Self::new(T::_1, T::_0);
+    const _2: Self = Self::new(T::_2, T::_0);
+
+    fn sqrt_(self) -> Self {
+        let re = self.re.sqrt_();
+        Self::new(re, self.du / (re + re))
+    }
+
+    fn powi_(self, n: i32) -> Self {
+        let mut prod = Self::_1;
+        for _ in 0..n.abs() {
+            prod *= self;
+        }
+        if n < 0 {
+            Self::_1 / prod
+        } else {
+            prod
+        }
+    }
+
+    fn hypot_(self, other: Self) -> Self {
+        let re = self.re.hypot_(other.re);
+        Self::new(re, (self.re * self.du + other.re * other.du) / re)
+    }
+
+    fn exp_(self) -> Self {
+        let re = self.re.exp_();
+        Self::new(re, re * self.du)
+    }
+
+    fn sin_(self) -> Self {
+        Self::new(self.re.sin_(), self.re.cos_() * self.du)
+    }
+
+    fn cos_(self) -> Self {
+        Self::new(self.re.cos_(), -self.re.sin_() * self.du)
+    }
+
+    fn is_nan_(self) -> bool {
+        self.re.is_nan_() || self.du.is_nan_()
+    }
+
+    fn is_infinite_(self) -> bool {
+        self.re.is_infinite_() || self.du.is_infinite_()
+    }
+}
+
+macro_rules! impl_dual_dot {
+    ($($t: ty),*) => {$(
+        /// Dot product of two dual vectors.
+        /// The dual part of the result is the derivative of the dot product.
+        ///
+        /// Dual vectors are interleaved `[re, du, re, du, ...]` in memory, so they are loaded as simd vectors of `$t`.
+        /// The real part is the sum of the even lanes of `a * b`,
+        /// and the dual part is the sum of the even lanes of `a * (b << 1) + (a << 1) * b`.
+        impl operations::DotProduct<Dual<$t>> for Rust {
+            fn dot<const LEN: usize>(
+                &self,
+                a: &impl StaticVec<Dual<$t>, LEN>,
+                b: &impl StaticVec<Dual<$t>, LEN>,
+            ) -> Dual<$t> {
+                const LANES: usize = crate::simd_lanes::max_for_type::<$t>();
+
+                let (a_ptr, b_ptr) = unsafe { (a.as_ptr() as *const $t, b.as_ptr() as *const $t) };
+
+                let mut re = Simd::<$t, LANES>::from_array([0.; LANES]);
+                let mut du = Simd::<$t, LANES>::from_array([0.; LANES]);
+                for n in 0..LEN * 2 / LANES {
+                    let (a, b) = unsafe {
+                        (
+                            Simd::from_slice(std::slice::from_raw_parts(a_ptr.add(n * LANES), LANES)),
+                            Simd::from_slice(std::slice::from_raw_parts(b_ptr.add(n * LANES), LANES)),
+                        )
+                    };
+                    re += a * b;
+                    du += a * b.rotate_lanes_left::<1>() + a.rotate_lanes_left::<1>() * b;
+                }
+
+                let (re, du) = (re.to_array(), du.to_array());
+                let mut sum = Dual::new(
+                    re.iter().step_by(2).sum(),
+                    du.iter().step_by(2).sum(),
+                );
+                for n in (LEN * 2 - (LEN * 2) % LANES) / 2..LEN {
+                    sum += unsafe { *a.get_unchecked(n) * *b.get_unchecked(n) }
+                }
+                sum
+            }
+        }
+
+        impl<'a, const LEN: usize> StaticVecUnion<'a, Dual<$t>, LEN> {
+            /// Dot product for two dual vectors.
+            /// There is no blas backend for dual numbers, so this will always use rust.
+            pub fn dot(&self, other: &Self) -> Dual<$t> {
+                Rust.dot(self, other)
+            }
+        }
+
+        impl Backend<Dual<$t>> for Rust {
+            fn supports(&self, op: BackendOp) -> bool {
+                use BackendOp::*;
+                matches!(op, DotProduct | MatrixVectorMul | TriangularSolve | Transpose)
+            }
+        }
+    )*};
+}
+
+impl_dual_dot!(f32, f64);
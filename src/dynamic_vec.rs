@@ -87,6 +87,24 @@ pub trait DynamicVec<T> {
     }
 
     /// Return a reference to self with the type of [`StaticVecUnion`]
+    ///
+    /// `LEN` is inferred from context, so a turbofish is only needed when nothing else constrains the length.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// fn sum4(v: StaticVecRef<f32, 4>) -> f32 {
+    ///     v.iter().sum()
+    /// }
+    ///
+    /// let a = vec![1f32, 2., 3., 4.];
+    /// assert_eq!(sum4(a.moo_ref()), 10.);
+    ///
+    /// // Nothing to infer the length from here.
+    /// let b = a.moo_ref::<4>();
+    /// assert_eq!(b[3], 4.);
+    /// ```
     fn moo_ref<'a, const LEN: usize>(&'a self) -> StaticVecRef<'a, T, LEN>
     where
         T: Copy,
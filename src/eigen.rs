@@ -0,0 +1,325 @@
+//! Eigenvalue and eigenvector algorithms.
+
+use crate::backends::operations;
+use crate::decompose::{abs, ORTHOGONALIZATION_EPSILON};
+use crate::prelude::*;
+
+/// Returns a vector of pseudo random numbers in the range `[-1, 1]`.
+/// The same seed is always used, so the result is deterministic.
+fn seeded_vec<T: Float + From<f32>, const N: usize>() -> [T; N] {
+    let mut state: u32 = 0x9E37_79B9;
+    let mut v = [T::_0; N];
+    for n in v.iter_mut() {
+        // xorshift32
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        *n = T::from(state as f32 / u32::MAX as f32 * 2. - 1.);
+    }
+    v
+}
+
+/// Maximum number of QR iterations used to diagonalize the tridiagonal matrix in [`lanczos`].
+/// With Wilkinson shifts each eigenvalue usually converges in a few iterations.
+pub const QR_MAX_ITERATIONS: usize = 1000;
+
+/// Error returned when power iteration fails to find an eigenpair.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerIterError {
+    /// The eigenvalue did not converge within the given number of iterations.
+    NotConverged,
+    /// The start vector is (numerically) spanned by the eigenvectors that were projected out,
+    /// fx. if all `N` eigenvectors have already been found.
+    Deflated,
+}
+
+impl std::fmt::Display for PowerIterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotConverged => write!(f, "Power iteration did not converge"),
+            Self::Deflated => write!(
+                f,
+                "Nothing is left of the start vector after projecting out the known eigenvectors"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PowerIterError {}
+
+/// Find the dominant eigenvalue (the one with the largest absolute value) and its eigenvector,
+/// of a symmetric matrix, using power iteration.
+///
+/// Starts from a deterministic pseudo random unit vector, and stops when the eigenvalue changes less than `tol`
+/// between two iterations. Returns [`PowerIterError::NotConverged`] if that doesn't happen within `max_iter` iterations.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::eigen::power_iteration;
+///
+/// let a = moo![f32: 2, 0, 0, 3].matrix::<slas_backend::Blas, 2, 2>();
+/// let (value, vector) = power_iteration(&a, 100, 1e-6).unwrap();
+///
+/// assert!((value - 3.).abs() < 1e-4);
+/// assert!(vector[0].abs() < 1e-2);
+/// ```
+pub fn power_iteration<
+    T: Float + PartialOrd + From<f32>,
+    U: StaticVec<T, LEN>,
+    B: Backend<T> + operations::MatrixVectorMul<T> + operations::Normalize<T, NormOutput = T>,
+    const N: usize,
+    const LEN: usize,
+>(
+    a: &Matrix<T, U, B, LEN, false, MatrixShape<N, N>>,
+    max_iter: usize,
+    tol: T,
+) -> Result<(T, [T; N]), PowerIterError> {
+    deflated_power_iteration(a, &[], max_iter, tol)
+}
+
+/// Same as [`power_iteration`], but `found` (orthonormal eigenvectors of `a`) are projected out of the iterate,
+/// so the dominant eigenpair of the remaining spectrum is found instead.
+/// Calling this repeatedly, with the previously found eigenvectors, computes the spectrum one eigenpair at a time.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::eigen::deflated_power_iteration;
+///
+/// let a = moo![f32: 2, 0, 0, 3].matrix::<slas_backend::Blas, 2, 2>();
+/// let (value, vector) = deflated_power_iteration(&a, &[[0., 1.]], 100, 1e-6).unwrap();
+///
+/// assert!((value - 2.).abs() < 1e-4);
+/// assert!(vector[1].abs() < 1e-2);
+/// ```
+pub fn deflated_power_iteration<
+    T: Float + PartialOrd + From<f32>,
+    U: StaticVec<T, LEN>,
+    B: Backend<T> + operations::MatrixVectorMul<T> + operations::Normalize<T, NormOutput = T>,
+    const N: usize,
+    const LEN: usize,
+>(
+    a: &Matrix<T, U, B, LEN, false, MatrixShape<N, N>>,
+    found: &[[T; N]],
+    max_iter: usize,
+    tol: T,
+) -> Result<(T, [T; N]), PowerIterError> {
+    let backend = a.backend();
+
+    let mut x = seeded_vec::<T, N>();
+    let start_norm = operations::Normalize::norm(backend, &x);
+    orthogonalize(&mut x, found);
+    let norm = operations::Normalize::norm(backend, &x);
+    if norm.is_nan_() || norm <= start_norm * <T as From<f32>>::from(ORTHOGONALIZATION_EPSILON) {
+        return Err(PowerIterError::Deflated);
+    }
+    x.iter_mut().for_each(|n| *n /= norm);
+
+    let mut value = T::_0;
+    for _ in 0..max_iter {
+        let mut y: [T; N] = a.vector_mul(&x);
+        orthogonalize(&mut y, found);
+
+        let mut next_value = T::_0;
+        for n in 0..N {
+            next_value += x[n] * y[n];
+        }
+
+        // x is in the null space of a (restricted to the remaining spectrum).
+        let norm = operations::Normalize::norm(backend, &y);
+        if norm.is_nan_() || norm <= T::_0 {
+            return Ok((T::_0, x));
+        }
+        for n in 0..N {
+            x[n] = y[n] / norm;
+        }
+
+        let converged = abs(next_value - value) < tol;
+        value = next_value;
+        if converged {
+            return Ok((value, x));
+        }
+    }
+    Err(PowerIterError::NotConverged)
+}
+
+/// Project the rows of `basis` out of `x`, using modified Gram-Schmidt.
+fn orthogonalize<T: Float, const N: usize>(x: &mut [T; N], basis: &[[T; N]]) {
+    for v in basis {
+        let mut d = T::_0;
+        for n in 0..N {
+            d += x[n] * v[n];
+        }
+        for n in 0..N {
+            x[n] -= d * v[n];
+        }
+    }
+}
+
+/// Approximate `K` eigenpairs of a symmetric matrix, using the Lanczos algorithm.
+///
+/// Builds an orthonormal basis of the `K`-dimensional Krylov subspace of `a`, using only matrix-vector products,
+/// and diagonalizes the projection of `a` onto it (a `K`x`K` tridiagonal matrix) with shifted QR iteration.
+/// The eigenvalues at the ends of the spectrum are approximated first, so this is useful when `N` is large,
+/// and only a few of the largest eigenvalues are needed. With `K = N` the whole spectrum is computed.
+///
+/// Returns the eigenvalues in descending order,
+/// and the corresponding eigenvectors as the columns of a row-major `N`x`K` matrix.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::eigen::lanczos;
+///
+/// let a = moo![f64: 2, 1, 1, 2].matrix::<slas_backend::Rust, 2, 2>();
+/// let (values, _) = lanczos::<f64, _, _, 2, 4, 2>(&a);
+///
+/// assert!((values[0] - 3.).abs() < 1e-6);
+/// assert!((values[1] - 1.).abs() < 1e-6);
+/// ```
+pub fn lanczos<
+    T: Float + PartialOrd + From<f32>,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>
+        + operations::MatrixVectorMul<T>
+        + operations::Normalize<T, NormOutput = T>
+        + operations::Axpy<T>,
+    const N: usize,
+    const LEN: usize,
+    const K: usize,
+>(
+    a: &Matrix<T, U, B, LEN, false, MatrixShape<N, N>>,
+) -> ([T; K], [T; N * K])
+where
+    [(); K * K]:,
+{
+    assert!(
+        K <= N,
+        "Cannot compute {} eigenvalues of a {}x{} matrix",
+        K,
+        N,
+        N
+    );
+    let backend = a.backend();
+    let eps = <T as From<f32>>::from(ORTHOGONALIZATION_EPSILON);
+
+    // Krylov basis (one vector per row) and the tridiagonal projection of a onto it.
+    let mut v = [[T::_0; N]; K];
+    let mut t = [T::_0; K * K];
+
+    let mut next = seeded_vec::<T, N>();
+    for j in 0..K {
+        // Orthogonalizing against the entire basis (instead of only the last two vectors),
+        // prevents loss of orthogonality because of rounding errors.
+        orthogonalize(&mut next, &v[..j]);
+        let mut norm = operations::Normalize::norm(backend, &next);
+
+        if norm.is_nan_() || norm <= eps {
+            // The basis spans an invariant subspace of a, so continue from the unit vector
+            // that is furthest from the subspace.
+            norm = T::_0;
+            for n in 0..N {
+                let mut e = [T::_0; N];
+                e[n] = T::_1;
+                orthogonalize(&mut e, &v[..j]);
+                let e_norm = operations::Normalize::norm(backend, &e);
+                if e_norm > norm {
+                    next = e;
+                    norm = e_norm;
+                }
+            }
+        } else if j > 0 {
+            t[j * K + j - 1] = norm;
+            t[(j - 1) * K + j] = norm;
+        }
+
+        v[j] = next.map(|n| n / norm);
+        next = a.vector_mul(&v[j]);
+        for (x, y) in next.iter().zip(&v[j]) {
+            t[j * K + j] += *x * *y;
+        }
+    }
+
+    // QR iteration with Wilkinson shifts: t = Q^T * t * Q converges to a diagonal matrix,
+    // and z accumulates the Q's (the eigenvectors of t).
+    // Each step is done implicitly with Givens rotations, on the lowest block of t that has not converged yet.
+    let mut z = [T::_0; K * K];
+    for n in 0..K {
+        z[n * K + n] = T::_1;
+    }
+    for _ in 0..QR_MAX_ITERATIONS {
+        for n in 1..K {
+            if abs(t[n * K + n - 1]) <= eps * (abs(t[n * K + n]) + abs(t[(n - 1) * K + n - 1])) {
+                t[n * K + n - 1] = T::_0;
+                t[(n - 1) * K + n] = T::_0;
+            }
+        }
+        let hi = match (1..K).rev().find(|&n| t[n * K + n - 1] != T::_0) {
+            Some(hi) => hi,
+            None => break,
+        };
+        let mut lo = hi - 1;
+        while lo > 0 && t[lo * K + lo - 1] != T::_0 {
+            lo -= 1;
+        }
+
+        // Eigenvalue of the trailing 2x2 block of t[lo..=hi] that is closest to its last diagonal element.
+        let (d, e) = (
+            (t[(hi - 1) * K + hi - 1] - t[hi * K + hi]) / (T::_1 + T::_1),
+            t[hi * K + hi - 1],
+        );
+        let root = (d * d + e * e).sqrt_();
+        let shift = t[hi * K + hi] - e * e / if d < T::_0 { d - root } else { d + root };
+
+        let mut x = t[lo * K + lo] - shift;
+        let mut y = t[(lo + 1) * K + lo];
+        for k in lo..hi {
+            let r = (x * x + y * y).sqrt_();
+            let (c, s) = if r == T::_0 {
+                (T::_1, T::_0)
+            } else {
+                (x / r, y / r)
+            };
+
+            // t = G * t * G^T and z = z * G^T, where G rotates row k + 1 into row k.
+            for n in 0..K {
+                let (a, b) = (t[k * K + n], t[(k + 1) * K + n]);
+                t[k * K + n] = c * a + s * b;
+                t[(k + 1) * K + n] = c * b - s * a;
+            }
+            for m in [&mut t, &mut z] {
+                for n in 0..K {
+                    let (a, b) = (m[n * K + k], m[n * K + k + 1]);
+                    m[n * K + k] = c * a + s * b;
+                    m[n * K + k + 1] = c * b - s * a;
+                }
+            }
+
+            // Chase the bulge created below the subdiagonal.
+            if k + 1 < hi {
+                x = t[(k + 1) * K + k];
+                y = t[(k + 2) * K + k];
+            }
+        }
+    }
+
+    let mut order: [usize; K] = std::array::from_fn(|n| n);
+    order.sort_by(|&i, &j| {
+        t[j * K + j]
+            .partial_cmp(&t[i * K + i])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut values = [T::_0; K];
+    let mut vectors = [T::_0; N * K];
+    for (col, &j) in order.iter().enumerate() {
+        values[col] = t[j * K + j];
+        for n in 0..N {
+            for i in 0..K {
+                vectors[n * K + col] += v[i][n] * z[i * K + j];
+            }
+        }
+    }
+    (values, vectors)
+}
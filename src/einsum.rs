@@ -0,0 +1,296 @@
+//! Einstein summation over tensors. See [`crate::einsum!`].
+
+use crate::prelude::*;
+use crate::tensor::Shape;
+
+/// Maximum number of distinct indices in an einsum expression.
+const MAX_INDICES: usize = 16;
+
+/// Parsed einsum expression, with the length of each index.
+struct Indices<'a> {
+    inputs: [&'a [u8]; 2],
+    output: &'a [u8],
+    letters: [u8; MAX_INDICES],
+    lens: [usize; MAX_INDICES],
+    count: usize,
+}
+
+impl<'a> Indices<'a> {
+    fn position(&self, letter: u8) -> usize {
+        self.letters[..self.count]
+            .iter()
+            .position(|&l| l == letter)
+            .unwrap()
+    }
+
+    /// Add the indices of an input term, and validate that they agree with the lengths already seen.
+    fn add_term<const NDIM: usize>(&mut self, term: &[u8], lens: [usize; NDIM]) {
+        assert_eq!(
+            term.len(),
+            NDIM,
+            "einsum term {:?} does not match tensor with {NDIM} dimensions",
+            std::str::from_utf8(term).unwrap()
+        );
+        for (&letter, len) in term.iter().zip(lens) {
+            match self.letters[..self.count].iter().position(|&l| l == letter) {
+                Some(i) => assert_eq!(
+                    self.lens[i], len,
+                    "einsum index '{}' has inconsistent lengths",
+                    letter as char
+                ),
+                None => {
+                    assert!(self.count < MAX_INDICES, "Too many einsum indices");
+                    self.letters[self.count] = letter;
+                    self.lens[self.count] = len;
+                    self.count += 1;
+                }
+            }
+        }
+    }
+
+    /// Index into the data of a term, given the value of each index and the strides of the term.
+    fn strided_index(
+        &self,
+        term: &[u8],
+        strides: &[usize],
+        values: &[usize; MAX_INDICES],
+    ) -> usize {
+        term.iter()
+            .zip(strides)
+            .map(|(&letter, stride)| values[self.position(letter)] * stride)
+            .sum()
+    }
+
+    /// Flat row-major index into a term, given the value of each index.
+    fn flat_index(&self, term: &[u8], values: &[usize; MAX_INDICES]) -> usize {
+        let mut i = 0;
+        for &letter in term {
+            let p = self.position(letter);
+            i = i * self.lens[p] + values[p];
+        }
+        i
+    }
+}
+
+/// Tensors that can be used as inputs to [`crate::einsum!`].
+///
+/// Axes are listed in the order they are named in an einsum term,
+/// so the first axis is the outermost one, which is the last axis of a slas tensor.
+pub trait EinsumInput<T, const NDIM: usize> {
+    /// Length of each axis.
+    fn term_lens(&self) -> [usize; NDIM];
+    /// Distance in [`EinsumInput::term_data`] between neighbouring elements along each axis.
+    fn term_strides(&self) -> [usize; NDIM];
+    fn term_data(&self) -> &[T];
+}
+
+impl<
+        T: Copy,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        S: Shape<NDIM>,
+        const NDIM: usize,
+        const LEN: usize,
+    > EinsumInput<T, NDIM> for Tensor<T, U, B, NDIM, LEN, S>
+{
+    fn term_lens(&self) -> [usize; NDIM] {
+        let mut lens = [0; NDIM];
+        for (n, len) in lens.iter_mut().enumerate() {
+            *len = self.shape.axis_len(NDIM - 1 - n);
+        }
+        lens
+    }
+
+    fn term_strides(&self) -> [usize; NDIM] {
+        let mut strides = [1; NDIM];
+        for n in (0..NDIM.saturating_sub(1)).rev() {
+            strides[n] = strides[n + 1] * self.shape.axis_len(NDIM - 2 - n);
+        }
+        strides
+    }
+
+    fn term_data(&self) -> &[T] {
+        &self.data.data.moo_ref()[..]
+    }
+}
+
+/// Lazily transposed matrices are read in their transposed order, without transposing the data.
+impl<
+        T: Copy,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        S: Shape<2>,
+        const LEN: usize,
+        const IS_TRANS: bool,
+    > EinsumInput<T, 2> for Matrix<T, U, B, LEN, IS_TRANS, S>
+{
+    fn term_lens(&self) -> [usize; 2] {
+        [self.rows(), self.columns()]
+    }
+
+    fn term_strides(&self) -> [usize; 2] {
+        if IS_TRANS {
+            [1, self.rows()]
+        } else {
+            [self.columns(), 1]
+        }
+    }
+
+    fn term_data(&self) -> &[T] {
+        self.0.term_data()
+    }
+}
+
+fn parse(spec: &str, n_inputs: usize) -> Indices<'_> {
+    let arrow = spec
+        .find("->")
+        .expect("einsum expression must contain '->'");
+    let (inputs, output) = (&spec.as_bytes()[..arrow], &spec.as_bytes()[arrow + 2..]);
+
+    assert_eq!(
+        inputs.split(|&c| c == b',').count(),
+        n_inputs,
+        "einsum expression {spec:?} does not have {n_inputs} input terms"
+    );
+    let mut terms = inputs.split(|&c| c == b',');
+    let first = terms.next().unwrap();
+    let second = terms.next().unwrap_or(&[]);
+
+    Indices {
+        inputs: [first, second],
+        output,
+        letters: [0; MAX_INDICES],
+        lens: [0; MAX_INDICES],
+        count: 0,
+    }
+}
+
+/// Loop over all combinations of index values, and sum the products of the inputs into the output.
+fn contract<T: Float, const OLEN: usize>(idx: &Indices, inputs: &[(&[T], &[usize])]) -> [T; OLEN] {
+    for &letter in idx.output {
+        assert!(
+            idx.letters[..idx.count].contains(&letter),
+            "einsum output index '{}' is not in any input",
+            letter as char
+        );
+    }
+    let out_len: usize = idx
+        .output
+        .iter()
+        .map(|&l| idx.lens[idx.position(l)])
+        .product();
+    assert_eq!(
+        out_len, OLEN,
+        "einsum expected output buffer of {out_len} elements, found one of {OLEN}"
+    );
+
+    let mut out = [T::_0; OLEN];
+    let mut values = [0; MAX_INDICES];
+    if idx.lens[..idx.count].contains(&0) {
+        return out;
+    }
+
+    loop {
+        let mut product = T::_1;
+        for (term, (data, strides)) in idx.inputs.iter().zip(inputs.iter()) {
+            product *= data[idx.strided_index(term, strides, &values)];
+        }
+        let o = idx.flat_index(idx.output, &values);
+        out[o] += product;
+
+        // Increment the index values like an odometer.
+        let mut n = 0;
+        loop {
+            if n == idx.count {
+                return out;
+            }
+            values[n] += 1;
+            if values[n] < idx.lens[n] {
+                break;
+            }
+            values[n] = 0;
+            n += 1;
+        }
+    }
+}
+
+/// General two tensor einsum. Use [`crate::einsum!`] instead of calling this directly.
+pub fn einsum2<
+    T: Float,
+    A: EinsumInput<T, NDIM1>,
+    B: EinsumInput<T, NDIM2>,
+    const NDIM1: usize,
+    const NDIM2: usize,
+    const OLEN: usize,
+>(
+    spec: &str,
+    a: &A,
+    b: &B,
+) -> [T; OLEN] {
+    let mut idx = parse(spec, 2);
+    idx.add_term(idx.inputs[0], a.term_lens());
+    idx.add_term(idx.inputs[1], b.term_lens());
+    contract(
+        &idx,
+        &[
+            (a.term_data(), &a.term_strides()[..]),
+            (b.term_data(), &b.term_strides()[..]),
+        ],
+    )
+}
+
+/// General single tensor einsum. Use [`crate::einsum!`] instead of calling this directly.
+pub fn einsum1<T: Float, A: EinsumInput<T, NDIM>, const NDIM: usize, const OLEN: usize>(
+    spec: &str,
+    a: &A,
+) -> [T; OLEN] {
+    let mut idx = parse(spec, 1);
+    idx.add_term(idx.inputs[0], a.term_lens());
+    contract(&idx, &[(a.term_data(), &a.term_strides()[..])])
+}
+
+/// Einstein summation over one or two tensors.
+///
+/// The expression uses numpy notation, where the first index of a term is the outermost axis
+/// (fx. the row of a matrix), and repeated indices that are not in the output are summed over.
+/// The output is returned as a flat row-major array, which can be reshaped afterwards.
+/// The length of the output is inferred from the context.
+/// The expression is parsed and validated against the shapes of the inputs at runtime, not at compile time,
+/// so an invalid expression panics.
+///
+/// Lazily transposed matrices are read in their transposed order.
+///
+/// Matrix multiplication (`"ij,jk->ik"`) is dispatched to [`Matrix::matrix_mul`],
+/// and all other expressions use a generic nested loop.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let a = moo![f32: 1..7].matrix::<slas_backend::Blas, 2, 3>();
+/// let b = moo![f32: 1..7].matrix::<slas_backend::Blas, 3, 2>();
+///
+/// let c: [f32; 4] = slas::einsum!("ij,jk->ik", &a, &b);
+/// assert_eq!(c, a.matrix_mul(&b));
+///
+/// let t: [f32; 6] = slas::einsum!("ij->ji", &a);
+/// assert_eq!(t, [1., 4., 2., 5., 3., 6.]);
+///
+/// let at: [f32; 6] = slas::einsum!("ij->ij", &a.transpose());
+/// assert_eq!(at, t);
+///
+/// let trace: [f32; 1] = slas::einsum!("ii->", &moo![f32: 1..5].matrix::<slas_backend::Blas, 2, 2>());
+/// assert_eq!(trace, [5.]);
+/// ```
+#[macro_export]
+macro_rules! einsum {
+    ("ij,jk->ik", $a: expr, $b: expr) => {
+        ($a).matrix_mul($b)
+    };
+    ($spec: literal, $a: expr, $b: expr) => {
+        $crate::einsum::einsum2($spec, $a, $b)
+    };
+    ($spec: literal, $a: expr) => {
+        $crate::einsum::einsum1($spec, $a)
+    };
+}
@@ -0,0 +1,12 @@
+//! Conversions between slas types and types from other linear algebra crates.
+//!
+//! Each crate has its own feature flag:
+//! - `nalgebra`: [`StaticCowVec`](crate::StaticCowVec) from/into `nalgebra::SVector`,
+//!   and [`Matrix`](crate::tensor::Matrix) from/into `nalgebra::SMatrix`.
+//! - `ndarray`: [`StaticCowVec`](crate::StaticCowVec) from/into `ndarray::Array1` and `ndarray::ArrayView1`,
+//!   and [`Matrix`](crate::tensor::Matrix) from/into `ndarray::Array2`.
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
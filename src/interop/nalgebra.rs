@@ -0,0 +1,72 @@
+//! Nalgebra stores fixed size vectors contiguously, so they can be borrowed as a [`StaticCowVec`] without copying.
+//! Fixed size nalgebra matricies are column-major, which means they need to be copied (and transposed) to and from slas matricies.
+//!
+//! ## Example
+//! ```rust
+//! use slas::prelude::*;
+//!
+//! let v = nalgebra::SVector::<f32, 3>::new(1., 2., 3.);
+//! let borrowed: StaticCowVec<f32, 3> = (&v).into();
+//! assert!(borrowed.is_borrowed());
+//! assert_eq!(borrowed.dot([1.; 3].moo_ref()), 6.);
+//!
+//! let back: nalgebra::SVector<f32, 3> = borrowed.into();
+//! assert_eq!(back, v);
+//!
+//! let m = nalgebra::SMatrix::<f32, 2, 3>::new(1., 2., 3., 4., 5., 6.);
+//! let s: Matrix<f32, [f32; 6], slas_backend::Rust, 6, false, MatrixShape<2, 3>> = m.into();
+//! assert_eq!(s[(0, 2)], 3.);
+//! assert_eq!(*s.vec_ref(), [1., 2., 3., 4., 5., 6.]);
+//!
+//! let back: nalgebra::SMatrix<f32, 2, 3> = s.into();
+//! assert_eq!(back, m);
+//! ```
+
+use crate::backends::Rust;
+use crate::prelude::*;
+use ::nalgebra::{ArrayStorage, SMatrix, SVector, Scalar};
+
+impl<'a, T: Scalar + Copy, const N: usize> From<SVector<T, N>> for StaticCowVec<'a, T, N> {
+    fn from(v: SVector<T, N>) -> Self {
+        Self::from(v.data.0[0])
+    }
+}
+
+/// Borrows the vector without copying.
+impl<'a, T: Scalar + Copy, const N: usize> From<&'a SVector<T, N>> for StaticCowVec<'a, T, N> {
+    fn from(v: &'a SVector<T, N>) -> Self {
+        Self::from(&v.data.0[0])
+    }
+}
+
+impl<'a, T: Scalar + Copy, const N: usize> From<StaticCowVec<'a, T, N>> for SVector<T, N> {
+    fn from(v: StaticCowVec<'a, T, N>) -> Self {
+        SVector::from_array_storage(ArrayStorage([**v]))
+    }
+}
+
+/// Copies the data. Panics if `M * K != LEN`.
+impl<T: Scalar + Copy, const M: usize, const K: usize, const LEN: usize> From<SMatrix<T, M, K>>
+    for Matrix<T, [T; LEN], Rust, LEN, false, MatrixShape<M, K>>
+where
+    Rust: Backend<T>,
+{
+    fn from(m: SMatrix<T, M, K>) -> Self {
+        std::array::from_fn::<T, LEN, _>(|i| m[(i / K, i % K)]).matrix::<Rust, M, K>()
+    }
+}
+
+impl<
+        T: Scalar + Copy,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const IS_TRANS: bool,
+        const M: usize,
+        const K: usize,
+    > From<Matrix<T, U, B, LEN, IS_TRANS, MatrixShape<M, K>>> for SMatrix<T, M, K>
+{
+    fn from(m: Matrix<T, U, B, LEN, IS_TRANS, MatrixShape<M, K>>) -> Self {
+        SMatrix::from_fn(|r, c| m[(r, c)])
+    }
+}
@@ -0,0 +1,108 @@
+//! Contiguous ndarray views can be borrowed as a [`StaticCowVec`] without copying,
+//! all other conversions copy the data.
+//!
+//! ## Example
+//! ```rust
+//! use slas::prelude::*;
+//! use ndarray::{array, Array1, Array2};
+//!
+//! let a = array![1f32, 2., 3.];
+//! let v: StaticCowVec<f32, 3> = a.view().into();
+//! assert!(v.is_borrowed());
+//! assert!(StaticCowVec::<f32, 4>::try_from_ndarray(a.view()).is_err());
+//!
+//! let back: Array1<f32> = v.into();
+//! assert_eq!(back, a);
+//!
+//! let m = array![[1f32, 2., 3.], [4., 5., 6.]];
+//! let s: Matrix<f32, [f32; 6], slas_backend::Rust, 6, false, [usize; 2]> = m.clone().into();
+//! assert_eq!(s.rows(), 2);
+//! assert_eq!(s[(1, 0)], 4.);
+//!
+//! let back: Array2<f32> = s.into();
+//! assert_eq!(back, m);
+//! ```
+
+use crate::backends::Rust;
+use crate::prelude::*;
+use crate::SliceLengthError;
+use ::ndarray::{Array1, Array2, ArrayView1};
+
+impl<'a, T: Copy, const LEN: usize> StaticCowVec<'a, T, LEN> {
+    /// Convert an ndarray view to a StaticCowVec, returning an error if it does not have `LEN` elements.
+    /// The data is borrowed if the view is contiguous, and copied otherwise.
+    pub fn try_from_ndarray(v: ArrayView1<'a, T>) -> Result<Self, SliceLengthError> {
+        if v.len() != LEN {
+            return Err(SliceLengthError {
+                expected: LEN,
+                found: v.len(),
+            });
+        }
+        match v.to_slice() {
+            Some(s) => Self::try_from_slice(s),
+            None => Ok(Self::from_fn(|n| v[n])),
+        }
+    }
+}
+
+/// Borrows the data if `v` is contiguous. Panics if `v` does not have `LEN` elements.
+impl<'a, T: Copy, const LEN: usize> From<ArrayView1<'a, T>> for StaticCowVec<'a, T, LEN> {
+    fn from(v: ArrayView1<'a, T>) -> Self {
+        Self::try_from_ndarray(v).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Copies the data. Panics if `v` does not have `LEN` elements.
+impl<'a, T: Copy, const LEN: usize> From<Array1<T>> for StaticCowVec<'a, T, LEN> {
+    fn from(v: Array1<T>) -> Self {
+        if v.len() != LEN {
+            panic!(
+                "{}",
+                SliceLengthError {
+                    expected: LEN,
+                    found: v.len(),
+                }
+            );
+        }
+        Self::from_fn(|n| v[n])
+    }
+}
+
+impl<'a, T: Copy, const LEN: usize> From<StaticCowVec<'a, T, LEN>> for Array1<T> {
+    fn from(v: StaticCowVec<'a, T, LEN>) -> Self {
+        Array1::from_vec(v.to_vec())
+    }
+}
+
+/// Copies the data. Panics if `m` does not have `LEN` elements.
+impl<T: Copy, const LEN: usize> From<Array2<T>>
+    for Matrix<T, [T; LEN], Rust, LEN, false, [usize; 2]>
+where
+    Rust: Backend<T>,
+{
+    fn from(m: Array2<T>) -> Self {
+        let (rows, columns) = m.dim();
+        assert_eq!(
+            rows * columns,
+            LEN,
+            "Cannot convert {rows}x{columns} ndarray to matrix with {LEN} elements"
+        );
+        std::array::from_fn::<T, LEN, _>(|n| m[(n / columns, n % columns)])
+            .reshape([columns, rows], Rust)
+            .matrix()
+    }
+}
+
+impl<
+        T: Copy,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        S: crate::tensor::Shape<2>,
+        const LEN: usize,
+        const IS_TRANS: bool,
+    > From<Matrix<T, U, B, LEN, IS_TRANS, S>> for Array2<T>
+{
+    fn from(m: Matrix<T, U, B, LEN, IS_TRANS, S>) -> Self {
+        Array2::from_shape_fn((m.rows(), m.columns()), |(r, c)| m[(r, c)])
+    }
+}
@@ -0,0 +1,85 @@
+//! Interpolation between static vectors.
+
+use crate::prelude::*;
+
+/// Element-wise linear interpolation `a + t * (b - a)`.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// assert_eq!(*lerp(&[0., 2.], &[2., 4.], 0.5), [1., 3.]);
+/// ```
+pub fn lerp<T: Float, const LEN: usize>(
+    a: &impl StaticVec<T, LEN>,
+    b: &impl StaticVec<T, LEN>,
+    t: T,
+) -> StaticVecUnion<'static, T, LEN> {
+    let mut out = StaticVecUnion {
+        owned: **a.moo_ref(),
+    };
+    lerp_in_place(&mut out, b, t);
+    out
+}
+
+/// Same as [`lerp`], but writes the result into `a`.
+pub fn lerp_in_place<T: Float, const LEN: usize>(
+    a: &mut impl StaticVec<T, LEN>,
+    b: &impl StaticVec<T, LEN>,
+    t: T,
+) {
+    let a = a.mut_moo_ref();
+    let b = b.moo_ref();
+    for n in 0..LEN {
+        a[n] = a[n] + t * (b[n] - a[n]);
+    }
+}
+
+/// Spherical linear interpolation between the unit vectors `a` and `b`.
+/// The result moves along the great circle between `a` and `b` with constant angular velocity.
+///
+/// Falls back to [`lerp`] if `a` and `b` are (nearly) parallel.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let s = slerp(&[1f32, 0.], &[0., 1.], 0.5);
+/// assert!((s[0] - 0.5f32.sqrt()).abs() < 1e-6);
+/// assert!((s[1] - 0.5f32.sqrt()).abs() < 1e-6);
+/// ```
+pub fn slerp<T: TranscendentalFloat + PartialOrd, const LEN: usize>(
+    a: &impl StaticVec<T, LEN>,
+    b: &impl StaticVec<T, LEN>,
+    t: T,
+) -> StaticVecUnion<'static, T, LEN> {
+    let (a, b) = (a.moo_ref(), b.moo_ref());
+
+    let mut dot = T::_0;
+    for n in 0..LEN {
+        dot += a[n] * b[n];
+    }
+    let dot = if dot > T::_1 {
+        T::_1
+    } else if dot < T::_0 - T::_1 {
+        T::_0 - T::_1
+    } else {
+        dot
+    };
+
+    let angle = dot.acos_();
+    let sin = angle.sin_();
+    if sin == T::_0 {
+        return lerp(a, b, t);
+    }
+
+    let wa = ((T::_1 - t) * angle).sin_() / sin;
+    let wb = (t * angle).sin_() / sin;
+    let mut out = StaticVecUnion {
+        owned: [T::_0; LEN],
+    };
+    for n in 0..LEN {
+        out[n] = wa * a[n] + wb * b[n];
+    }
+    out
+}
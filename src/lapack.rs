@@ -0,0 +1,135 @@
+//! Wrappers around the LAPACK routines used by slas, using the bindings from [lapacke-sys](https://github.com/blas-lapack-rs/lapacke-sys).
+//!
+//! LAPACK is provided by most blas implementations (fx. openblas), but not by all of them (fx. blis),
+//! which is why this module is behind the `lapack` feature.
+//! All routines expect matricies to be stored in column-major order.
+
+use lapacke_sys::*;
+use std::os::raw::c_char;
+
+/// Float types with LAPACK support.
+pub trait Lapack: crate::num::Float {
+    /// LU factorization with partial pivoting (`sgetrf`/`dgetrf`).
+    ///
+    /// # Safety
+    /// Is safe as long as `a` points to a column-major `m`x`n` matrix with leading dimension `lda`,
+    /// and `ipiv` points to at least `min(m, n)` elements.
+    unsafe fn getrf(m: usize, n: usize, a: *mut Self, lda: usize, ipiv: *mut i32) -> i32;
+
+    /// Inverse of a matrix from its LU factorization (`sgetri`/`dgetri`).
+    ///
+    /// # Safety
+    /// Is safe as long as `a` and `ipiv` are the output of [`Lapack::getrf`] for a `n`x`n` matrix.
+    unsafe fn getri(n: usize, a: *mut Self, lda: usize, ipiv: *const i32) -> i32;
+
+    /// QR factorization (`sgeqrf`/`dgeqrf`).
+    ///
+    /// # Safety
+    /// Is safe as long as `a` points to a column-major `m`x`n` matrix with leading dimension `lda`,
+    /// and `tau` points to at least `min(m, n)` elements.
+    unsafe fn geqrf(m: usize, n: usize, a: *mut Self, lda: usize, tau: *mut Self) -> i32;
+
+    /// Generate the orthogonal matrix Q from the output of [`Lapack::geqrf`] (`sorgqr`/`dorgqr`).
+    ///
+    /// # Safety
+    /// Is safe as long as `a` and `tau` contain `k` elementary reflectors from [`Lapack::geqrf`],
+    /// and `a` points to a column-major `m`x`n` matrix.
+    unsafe fn orgqr(
+        m: usize,
+        n: usize,
+        k: usize,
+        a: *mut Self,
+        lda: usize,
+        tau: *const Self,
+    ) -> i32;
+
+    /// Full singular value decomposition (`sgesvd`/`dgesvd`), computing all columns of U and all rows of V^T.
+    /// `a` is overwritten.
+    ///
+    /// # Safety
+    /// Is safe as long as `a` points to a column-major `m`x`n` matrix with leading dimension `lda`,
+    /// `s` points to at least `min(m, n)` elements, `u` to a `m`x`m` matrix with leading dimension `ldu`,
+    /// `vt` to a `n`x`n` matrix with leading dimension `ldvt`, and `superb` to at least `min(m, n) - 1` elements.
+    #[allow(clippy::too_many_arguments)]
+    unsafe fn gesvd(
+        m: usize,
+        n: usize,
+        a: *mut Self,
+        lda: usize,
+        s: *mut Self,
+        u: *mut Self,
+        ldu: usize,
+        vt: *mut Self,
+        ldvt: usize,
+        superb: *mut Self,
+    ) -> i32;
+}
+
+macro_rules! impl_lapack {
+    ($t: ty: $getrf: ident $getri: ident $geqrf: ident $orgqr: ident $gesvd: ident) => {
+        impl Lapack for $t {
+            unsafe fn getrf(m: usize, n: usize, a: *mut Self, lda: usize, ipiv: *mut i32) -> i32 {
+                $getrf(LAPACK_COL_MAJOR, m as i32, n as i32, a, lda as i32, ipiv)
+            }
+
+            unsafe fn getri(n: usize, a: *mut Self, lda: usize, ipiv: *const i32) -> i32 {
+                $getri(LAPACK_COL_MAJOR, n as i32, a, lda as i32, ipiv)
+            }
+
+            unsafe fn geqrf(m: usize, n: usize, a: *mut Self, lda: usize, tau: *mut Self) -> i32 {
+                $geqrf(LAPACK_COL_MAJOR, m as i32, n as i32, a, lda as i32, tau)
+            }
+
+            unsafe fn orgqr(
+                m: usize,
+                n: usize,
+                k: usize,
+                a: *mut Self,
+                lda: usize,
+                tau: *const Self,
+            ) -> i32 {
+                $orgqr(
+                    LAPACK_COL_MAJOR,
+                    m as i32,
+                    n as i32,
+                    k as i32,
+                    a,
+                    lda as i32,
+                    tau,
+                )
+            }
+
+            unsafe fn gesvd(
+                m: usize,
+                n: usize,
+                a: *mut Self,
+                lda: usize,
+                s: *mut Self,
+                u: *mut Self,
+                ldu: usize,
+                vt: *mut Self,
+                ldvt: usize,
+                superb: *mut Self,
+            ) -> i32 {
+                $gesvd(
+                    LAPACK_COL_MAJOR,
+                    b'A' as c_char,
+                    b'A' as c_char,
+                    m as i32,
+                    n as i32,
+                    a,
+                    lda as i32,
+                    s,
+                    u,
+                    ldu as i32,
+                    vt,
+                    ldvt as i32,
+                    superb,
+                )
+            }
+        }
+    };
+}
+
+impl_lapack!(f32: LAPACKE_sgetrf LAPACKE_sgetri LAPACKE_sgeqrf LAPACKE_sorgqr LAPACKE_sgesvd);
+impl_lapack!(f64: LAPACKE_dgetrf LAPACKE_dgetri LAPACKE_dgeqrf LAPACKE_dorgqr LAPACKE_dgesvd);
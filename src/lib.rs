@@ -168,7 +168,7 @@
 //! let t = moo![f32: 0..27].reshape([3, 3, 3], slas_backend::Rust);
 //! assert_eq!(t[[0, 0, 1]], 9.);
 //!
-//! let mut s = t.index_slice(1).matrix();
+//! let mut s = t.index_slice::<9>(1).matrix();
 //!
 //! assert_eq!(s[(0, 0)], 9.);
 //! assert_eq!(s.transpose()[(1, 0)], 10.);
@@ -204,6 +204,10 @@
 //! This mean you will need to disable slas default features,
 //! follow the installation instructions in the openblas readme and add `extern crate openblas_src` to your main file.
 //!
+//! The `lapack` feature enables the functions that depend on LAPACK (`decompose::svd`, `Matrix::pinv`, `Matrix::qr_blas`,
+//! `decompose::lu_blas` and matrix inversion on the blas backend), using [lapacke-sys](https://github.com/blas-lapack-rs/lapacke-sys).
+//! Blis does not ship LAPACK, so you will need to link a LAPACK provider yourself (fx. openblas or `apt install liblapacke-dev`).
+//!
 //! # Enviroment variables
 //!
 //! The backend being chosen to use when none is specified, depends on environment variables.
@@ -214,10 +218,26 @@
 //!
 //! Again, this is only applicable when no backend is not specified for a vector (fx `moo![f32: 1, 2].dot(moo![2, 1])`).
 //!
+//! Other variables tune how the rust backend performs operations:
+//! - `SLAS_BLAS_IN_GEMM_IF_SIZE_GE` is the smallest `m * n * k` for which matrix multiplication on the blas backend calls blas, instead of the rust backend.
+//!   Smaller matricies are multiplied with a simple loop, which avoids the overhead of calling blas.
+//! - `SLAS_SIMD_IN_ADD_IF_LEN_GE` is the smallest length for which element-wise operations (`add`, `sub`, `mul` and `div`) on the rust backend use simd.
+//!
+//! - `SLAS_DEFAULT_BACKEND` (`Blas` or `Rust`) is the backend used by `moo![auto ...]`,
+//!   and can be found as the type alias `slas::config::DefaultBackend`.
+//!   Defaults to `Blas` if the `blas` feature is enabled, and `Rust` otherwise.
+//!
+//! All of them can be found as constants in `slas::config`.
+//! The best values depend on your hardware, so the easiest way to tune them is to benchmark your own code with a few different values.
+//! Alternatively the `profile` feature can be enabled, and a function can be annotated with `#[slas::profile]`,
+//! which will print suggested values based on how that function performs.
+//!
 //! ## Variables and default values
 //!
 //! ```shell
 //! SLAS_BLAS_IN_DOT_IF_LEN_GE = 750
+//! SLAS_BLAS_IN_GEMM_IF_SIZE_GE = 512
+//! SLAS_SIMD_IN_ADD_IF_LEN_GE = 8
 //! ```
 //!
 //! ## Possible issues during linking
@@ -243,12 +263,50 @@
     const_mut_refs
 )]
 
+#[cfg(feature = "bytemuck")]
+pub mod bytes;
+pub mod complex;
 pub mod config;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod decompose;
+pub mod distance;
+pub mod dual;
+pub mod eigen;
+pub mod einsum;
+pub mod interop;
+pub mod interpolate;
+#[cfg(feature = "lapack")]
+pub mod lapack;
+pub mod ml;
 mod nullvec;
+pub mod poly;
 pub mod prelude;
+#[cfg(feature = "profile")]
+pub mod profile;
+pub mod signal;
 pub mod simd_lanes;
+pub mod solvers;
+pub mod stats;
+pub mod tags;
 pub mod tensor;
+pub mod transform;
+mod unit_vec;
 pub use nullvec::*;
+pub use unit_vec::*;
+
+/// Instrument a function, to suggest values for the `SLAS_*` environment variables based on how it performs.
+/// See [`mod@profile`] for more information.
+///
+/// Suggestions are printed to stderr every `runs` calls to the function (default is 100).
+///
+/// ## Example
+/// ```rust
+/// #[slas::profile(runs = 10)]
+/// fn train_step() {}
+/// ```
+#[cfg(feature = "profile")]
+pub use slas_macros::profile;
 mod dynamic_vec;
 mod static_vec;
 
@@ -263,7 +321,6 @@ use std::{
 extern crate blis_src;
 #[cfg(feature = "blas")]
 extern crate cblas_sys;
-use prelude::*;
 
 /// StaticVectorUnion is always owned when it is not found in a StaticCowVec,
 /// therefore we have this type alias to make it less confisung when dealing with references to owned vectors.
@@ -281,6 +338,8 @@ pub type MutStaticVecRef<'a, T, const LEN: usize> = &'a mut StaticVecUnion<'a, T
 /// assert_eq!(**moo![f32: 1..4], [1., 2., 3.]);
 /// assert_eq!(**moo![f32: 1..=3], [1., 2., 3.]);
 /// assert_eq!(**moo![0f32; 4], [0.; 4]);
+/// assert_eq!(**moo![f32: 2; 3], [2., 2., 2.]);
+/// assert!(moo![f32::NAN; 10].iter().all(|n| n.is_nan()));
 ///
 /// let mut tmp = [0.; 100];
 /// for n in 0..100{
@@ -289,20 +348,37 @@ pub type MutStaticVecRef<'a, T, const LEN: usize> = &'a mut StaticVecUnion<'a, T
 ///
 /// assert_eq!(**moo![|n|-> f32 { (n as f32).sin() }; 100], tmp);
 /// assert_eq!(**moo![|n| (n as f32).sin(); 100], tmp);
+///
+/// // Extra elements are discarded, but the iterator must yield atleast `LEN` elements.
+/// assert_eq!(**moo![f32, 3: (0..10).map(|n| n as f32 / 2.)], [0., 0.5, 1.]);
+///
+/// let scale = 2.;
+/// assert_eq!(**moo![move |i: usize| -> f32 { i as f32 * scale }; 3], [0., 2., 4.]);
+///
+/// // Use the backend selected by `SLAS_DEFAULT_BACKEND` during build.
+/// let a = moo![auto f32: 1..=3];
+/// assert_eq!(a.dot(&a), 14.);
 /// ```
 #[macro_export]
 macro_rules! moo {
-    (|$n: ident| -> $t: ty $do: block ; $len: expr) => {{
-        let mut tmp = StaticCowVec::<$t, $len>::from([<$t>::_0; $len]);
-        (0..$len).map(|$n| -> f32 {$do}).enumerate().for_each(|(n, v)| tmp[n]=v);
-        tmp
+    (|$n: ident $(: $nt: ty)?| -> $t: ty $do: block ; $len: expr) => {{
+        StaticCowVec::<$t, $len>::from_fn(|$n $(: $nt)?| -> $t {$do})
     }};
-    (|$n: ident| $do: expr ; $len: expr) => {{
-        moo![|$n| -> _ {$do}; $len]
+    (move |$n: ident $(: $nt: ty)?| -> $t: ty $do: block ; $len: expr) => {{
+        StaticCowVec::<$t, $len>::from_fn(move |$n $(: $nt)?| -> $t {$do})
+    }};
+    (|$n: ident $(: $nt: ty)?| $do: expr ; $len: expr) => {{
+        moo![|$n $(: $nt)?| -> _ {$do}; $len]
+    }};
+    (move |$n: ident $(: $nt: ty)?| $do: expr ; $len: expr) => {{
+        moo![move |$n $(: $nt)?| -> _ {$do}; $len]
     }};
     (on $backend:ty : $($v: tt)*) => {{
         moo![$($v)*].static_backend::<$backend>()
     }};
+    (auto $($v: tt)*) => {{
+        moo![on $crate::backends::DefaultBackend: $($v)*]
+    }};
     (_ $($v: tt)*) => {{
         StaticCowVec::from($($v)*)
     }};
@@ -316,6 +392,17 @@ macro_rules! moo {
         tmp.iter_mut().zip($a..=$b).for_each(|(o, i)| *o = i as $t);
         tmp
     }};
+    ($t: ty, $len: tt : $iter: expr) => {{
+        let mut iter = IntoIterator::into_iter($iter);
+        StaticCowVec::<$t, $len>::from(std::array::from_fn::<$t, $len, _>(|n| {
+            iter.next().unwrap_or_else(|| {
+                panic!("Cannot create StaticCowVec of length {} from iterator with {n} elements", $len)
+            })
+        }))
+    }};
+    ($t: ty: $v: expr; $len: expr) => {{
+        StaticCowVec::from([$v as $t; $len])
+    }};
     ($t: ty: $($v: expr),* $(,)?) => {{
         StaticCowVec::from([$( $v as $t ),*])
     }};
@@ -347,6 +434,15 @@ impl<'a, T: Copy, const LEN: usize> StaticVecUnion<'a, T, LEN> {
         transmute(self)
     }
 
+    /// Return pointer to first element. Same as [`StaticVec::as_ptr`], but can be used in const contexts.
+    ///
+    /// # Safety
+    /// Is always safe, as a StaticVecUnion outside of a [`StaticCowVec`] is always owned.
+    #[inline(always)]
+    pub const unsafe fn as_ptr(&self) -> *const T {
+        &self.owned as *const [T; LEN] as *const T
+    }
+
     #[inline(always)]
     pub const fn len(&self) -> usize {
         LEN
@@ -408,7 +504,183 @@ impl<'a, T: Copy, const LEN: usize> StaticCowVec<'a, T, LEN> {
         self.is_owned
     }
 
+    /// Return pointer to first element. Same as [`StaticVec::as_ptr`], but can be used in const contexts.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// const DATA: [f32; 3] = [1., 2., 3.];
+    /// const SECOND: f32 = unsafe { *StaticCowVec::from_ref(&DATA).as_ptr().add(1) };
+    /// assert_eq!(SECOND, 2.);
+    /// ```
+    ///
+    /// # Safety
+    /// Is always safe, the pointer is just invalidated if self is borrowed and then mutated.
+    #[inline(always)]
+    pub const unsafe fn as_ptr(&self) -> *const T {
+        if self.is_owned {
+            self.data.as_ptr()
+        } else {
+            self.data.borrowed as *const T
+        }
+    }
+
+    /// Return the elements of self as a slice, reading the owned or borrowed data directly.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let data = [1., 2., 3.];
+    /// let v = data.moo();
+    /// assert_eq!(v.as_slice(), &data[..]);
+    /// assert!(v.is_borrowed());
+    /// ```
+    #[inline(always)]
+    pub const fn as_slice(&self) -> &[T] {
+        unsafe { std::slice::from_raw_parts(self.as_ptr(), LEN) }
+    }
+
+    /// Return the elements of self as a mutable slice.
+    /// If self is borrowed, the data is copied first, so the borrowed data is never mutated.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let data = [1., 2., 3.];
+    /// let mut v = data.moo();
+    /// v.as_slice_mut()[1..].fill(0.);
+    ///
+    /// assert_eq!(**v, [1., 0., 0.]);
+    /// assert_eq!(data, [1., 2., 3.]);
+    /// ```
+    #[inline(always)]
+    pub fn as_slice_mut(&mut self) -> &mut [T] {
+        &mut self.deref_mut()[..]
+    }
+
+    /// Create an owned StaticCowVec by calling `f` with the index of each element,
+    /// like [`std::array::from_fn`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let v = StaticCowVec::<f32, 4>::from_fn(|n| n as f32 * 0.5);
+    /// assert_eq!(**v, [0., 0.5, 1., 1.5]);
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        Self::from(std::array::from_fn(f))
+    }
+
+    /// Convert self into an owned StaticCowVec, that doesn't borrow any data.
+    /// Borrowed data is copied once, and owned data is returned as is,
+    /// so converting before mutating never copies more than mutating through [`DerefMut`] would.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let data = [1., 2., 3.];
+    /// let mut v = data.moo().into_owned();
+    /// assert!(v.is_owned());
+    ///
+    /// v[0] = 0.;
+    /// assert_eq!(**v, [0., 2., 3.]);
+    /// assert_eq!(data, [1., 2., 3.]);
+    /// ```
+    pub fn into_owned<'b>(self) -> StaticCowVec<'b, T, LEN> {
+        if self.is_owned {
+            StaticCowVec::from(unsafe { self.data.owned })
+        } else {
+            StaticCowVec::from(unsafe { *self.data.borrowed })
+        }
+    }
+
+    /// Mutable iterator over the elements of self.
+    /// If self is borrowed, the data is copied first, so the borrowed data is never mutated.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let data = [1., 2., 3.];
+    /// let mut v = data.moo();
+    /// v.iter_mut_cow().for_each(|n| *n *= 2.);
+    ///
+    /// assert_eq!(**v, [2., 4., 6.]);
+    /// assert_eq!(data, [1., 2., 3.]);
+    /// ```
+    pub fn iter_mut_cow(&mut self) -> std::slice::IterMut<'_, T> {
+        self.deref_mut().iter_mut()
+    }
+
+    /// Iterate over every window of `W` consecutive elements in self, as [`StaticVecRef`]s.
+    /// The windows point into self, so no data is copied,
+    /// and they can be passed directly to backend operations.
+    ///
+    /// Fails to compile if `W > LEN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let kernel = moo![f32: 1, -1];
+    /// let v = moo![f32: 1, 2, 4, 8];
+    /// let diffs: Vec<f32> = v.windows_ref::<2>().map(|w| w.dot(&kernel)).collect();
+    /// assert_eq!(diffs, [-1., -2., -4.]);
+    /// ```
+    pub fn windows_ref<const W: usize>(&self) -> impl Iterator<Item = StaticVecRef<'_, T, W>>
+    where
+        [(); LEN - W + 1]:,
+    {
+        let ptr = unsafe { self.as_ptr() };
+        (0..LEN - W + 1)
+            .map(move |n| prelude::StaticVec::moo_ref(unsafe { &*(ptr.add(n) as *const [T; W]) }))
+    }
+
+    /// Borrow a slice as a StaticCowVec, returning an error instead of panicking if the length of the slice is not `LEN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let data = vec![1., 2., 3.];
+    /// assert_eq!(**StaticCowVec::<f32, 3>::try_from_slice(&data).unwrap(), [1., 2., 3.]);
+    ///
+    /// let err = StaticCowVec::<f32, 4>::try_from_slice(&data).unwrap_err();
+    /// assert_eq!(err, SliceLengthError { expected: 4, found: 3 });
+    /// ```
+    pub fn try_from_slice(s: &'a [T]) -> Result<Self, SliceLengthError> {
+        if s.len() != LEN {
+            return Err(SliceLengthError {
+                expected: LEN,
+                found: s.len(),
+            });
+        }
+        Ok(Self::from(s))
+    }
+
+    /// Borrow an array as a StaticCowVec. Same as [`From<&[T; LEN]>`](From), but can be used without type annotations.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let data = [1f32, 2., 3.];
+    /// let v = StaticCowVec::from_ref(&data);
+    /// assert!(v.is_borrowed());
+    /// assert_eq!(v.dot([1.; 3].moo_ref()), 6.);
+    /// ```
+    pub const fn from_ref(r: &'a [T; LEN]) -> Self {
+        Self::from(r)
+    }
+
     /// Cast StaticCowVec from pointer.
+    /// See [`StaticCowVec::from_ref`] for a safe alternative,
+    /// if you don't need to mutate the source while the StaticCowVec exists.
     ///
     /// # Safety
     /// Is safe as long as `*ptr` is contiguous and `*ptr` has a length of `LEN`.
@@ -430,6 +702,25 @@ impl<'a, T: Copy, const LEN: usize> StaticCowVec<'a, T, LEN> {
     }
 }
 
+/// Error returned when a slice does not have the length of the static vector it is converted to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SliceLengthError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl std::fmt::Display for SliceLengthError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Cannot convert slice of length {} to static vector of length {}",
+            self.found, self.expected
+        )
+    }
+}
+
+impl std::error::Error for SliceLengthError {}
+
 impl<'a, T: Copy, const LEN: usize> const Deref for StaticCowVec<'a, T, LEN> {
     type Target = StaticVecUnion<'a, T, LEN>;
 
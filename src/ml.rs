@@ -0,0 +1,260 @@
+//! Functions commonly used in machine learning.
+
+use crate::backends::{operations, Rust};
+use crate::prelude::*;
+
+/// Returns the largest element of `v`.
+pub(crate) fn max_element<T: Copy + PartialOrd, const LEN: usize>(v: &[T; LEN]) -> T {
+    let mut max = v[0];
+    for &n in v.iter().skip(1) {
+        if n > max {
+            max = n;
+        }
+    }
+    max
+}
+
+impl<'a, T: Float + PartialOrd + std::iter::Sum, const LEN: usize> StaticVecUnion<'a, T, LEN>
+where
+    Rust: operations::VectorizedTranscendental<T>,
+{
+    /// Numerically stable softmax.
+    /// The largest element is subtracted from all elements before exponentiating,
+    /// so large inputs don't overflow.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let s = moo![f32: 1, 1].softmax();
+    /// assert_eq!(*s, [0.5, 0.5]);
+    /// ```
+    pub fn softmax(&self) -> StaticVecUnion<'static, T, LEN> {
+        let max = max_element(self);
+
+        let mut shifted = [T::_0; LEN];
+        for n in 0..LEN {
+            shifted[n] = self[n] - max;
+        }
+
+        let mut out = StaticVecUnion {
+            owned: [T::_0; LEN],
+        };
+        operations::VectorizedTranscendental::vexp(&Rust, &shifted, &mut out);
+
+        let sum: T = out.iter().copied().sum();
+        for n in out.iter_mut() {
+            *n /= sum;
+        }
+        out
+    }
+
+    /// Numerically stable `ln(sum(exp(x)))`, computed as `m + ln(sum(exp(x - m)))` where `m` is the largest element.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let v = moo![f32: 1000, 1000];
+    /// assert!((v.log_sum_exp() - (1000. + 2f32.ln())).abs() < 1e-3);
+    /// ```
+    pub fn log_sum_exp(&self) -> T {
+        let max = max_element(self);
+
+        let mut shifted = [T::_0; LEN];
+        for n in 0..LEN {
+            shifted[n] = self[n] - max;
+        }
+
+        let mut exp = [T::_0; LEN];
+        operations::VectorizedTranscendental::vexp(&Rust, &shifted, &mut exp);
+
+        let sum = [exp.iter().copied().sum::<T>()];
+        let mut ln = [T::_0];
+        operations::VectorizedTranscendental::vln(&Rust, &sum, &mut ln);
+        max + ln[0]
+    }
+}
+
+/// Element-wise natural logarithm of `a`, where the elements for which `mask` is false are replaced by 1,
+/// so that they have a logarithm of 0. This is used to evaluate `0 * ln(0)` as 0.
+fn masked_ln<T: Float, const LEN: usize>(a: [T; LEN], mask: impl Fn(usize) -> bool) -> [T; LEN]
+where
+    Rust: operations::VectorizedTranscendental<T>,
+{
+    let mut masked = a;
+    for (n, v) in masked.iter_mut().enumerate() {
+        if !mask(n) {
+            *v = T::_1;
+        }
+    }
+    let mut ln = [T::_0; LEN];
+    operations::VectorizedTranscendental::vln(&Rust, &masked, &mut ln);
+    ln
+}
+
+/// Kullback-Leibler divergence `sum(p * ln(p / q))` of the probability vector `q` from `p`.
+/// Terms where `p` is 0 are 0.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let p = [0.5f32, 0.5, 0.];
+/// assert_eq!(kl_divergence(&p, &p), 0.);
+/// assert!((kl_divergence(&p, &[0.25, 0.25, 0.5]) - 2f32.ln()).abs() < 1e-6);
+/// ```
+pub fn kl_divergence<T: Float + PartialOrd + std::iter::Sum, const LEN: usize>(
+    p: &impl StaticVec<T, LEN>,
+    q: &impl StaticVec<T, LEN>,
+) -> T
+where
+    Rust: operations::VectorizedTranscendental<T>,
+{
+    let (p, q) = (p.moo_ref(), q.moo_ref());
+    let ratio: [T; LEN] = std::array::from_fn(|n| p[n] / q[n]);
+    let ln = masked_ln(ratio, |n| p[n] > T::_0);
+    (0..LEN).map(|n| p[n] * ln[n]).sum()
+}
+
+/// Cross-entropy `-sum(p * ln(q))` of the predicted probabilities `q` relative to the true probabilities `p`.
+/// Terms where `p` is 0 are 0.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let p = [0f32, 1., 0.];
+/// assert!((cross_entropy(&p, &[0.25, 0.5, 0.25]) - 2f32.ln()).abs() < 1e-6);
+/// assert_eq!(cross_entropy(&p, &[0., 1., 0.]), 0.);
+/// ```
+pub fn cross_entropy<T: Float + PartialOrd + std::iter::Sum, const LEN: usize>(
+    p: &impl StaticVec<T, LEN>,
+    q: &impl StaticVec<T, LEN>,
+) -> T
+where
+    Rust: operations::VectorizedTranscendental<T>,
+{
+    let (p, q) = (p.moo_ref(), q.moo_ref());
+    let ln = masked_ln(**q, |n| p[n] > T::_0);
+    T::_0 - (0..LEN).map(|n| p[n] * ln[n]).sum::<T>()
+}
+
+/// Mean binary cross-entropy `-mean(y * ln(y') + (1 - y) * ln(1 - y'))`
+/// of the predicted probabilities `y_pred` for the labels `y_true`.
+/// Terms where the label weight (`y` or `1 - y`) is 0 are 0.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let y = [1f32, 0.];
+/// assert_eq!(binary_cross_entropy(&y, &y), 0.);
+/// assert!((binary_cross_entropy(&y, &[0.5, 0.5]) - 2f32.ln()).abs() < 1e-6);
+/// ```
+pub fn binary_cross_entropy<T: Float + PartialOrd + std::iter::Sum, const LEN: usize>(
+    y_true: &impl StaticVec<T, LEN>,
+    y_pred: &impl StaticVec<T, LEN>,
+) -> T
+where
+    Rust: operations::VectorizedTranscendental<T>,
+{
+    let (y, p) = (y_true.moo_ref(), y_pred.moo_ref());
+    let ln_p = masked_ln(**p, |n| y[n] > T::_0);
+    let ln_not_p: [T; LEN] = masked_ln(std::array::from_fn(|n| T::_1 - p[n]), |n| y[n] < T::_1);
+
+    let sum: T = (0..LEN)
+        .map(|n| y[n] * ln_p[n] + (T::_1 - y[n]) * ln_not_p[n])
+        .sum();
+    T::_0 - sum / T::from_f64(LEN as f64)
+}
+
+/// Mean and population variance of the elements of `x`.
+fn moments<T: Float + std::iter::Sum, const LEN: usize>(x: &[T; LEN]) -> (T, T) {
+    let len = T::from_f64(LEN as f64);
+    let mean = x.iter().copied().sum::<T>() / len;
+    let var = x.iter().map(|&n| (n - mean) * (n - mean)).sum::<T>() / len;
+    (mean, var)
+}
+
+/// Write `gamma * (x - mean) / sqrt(var + eps) + beta` into `x`.
+fn normalize_affine<T: Float, const LEN: usize>(
+    x: &mut impl StaticVec<T, LEN>,
+    gamma: &impl StaticVec<T, LEN>,
+    beta: &impl StaticVec<T, LEN>,
+    mean: T,
+    var: T,
+    eps: T,
+) {
+    let (gamma, beta) = (gamma.moo_ref(), beta.moo_ref());
+    let std = (var + eps).sqrt_();
+    let x = x.mut_moo_ref();
+    for n in 0..LEN {
+        x[n] = gamma[n] * (x[n] - mean) / std + beta[n];
+    }
+}
+
+/// Batch normalization forward pass.
+/// Normalizes `x` to zero mean and unit variance, then scales it by `gamma` and shifts it by `beta` element-wise.
+/// `eps` is added to the variance to avoid division by zero.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let mut x = [1f32, 3.];
+/// batch_norm_inplace(&mut x, &[1.; 2], &[0.; 2], 0.);
+/// assert_eq!(x, [-1., 1.]);
+/// ```
+pub fn batch_norm_inplace<T: Float + std::iter::Sum, const LEN: usize>(
+    x: &mut impl StaticVec<T, LEN>,
+    gamma: &impl StaticVec<T, LEN>,
+    beta: &impl StaticVec<T, LEN>,
+    eps: T,
+) {
+    let (mean, var) = moments(x.moo_ref());
+    normalize_affine(x, gamma, beta, mean, var, eps);
+}
+
+/// Batch normalization with running statistics.
+///
+/// When `training` is true, `x` is normalized with its own mean and variance, like [`batch_norm_inplace`],
+/// and the running statistics are updated as `running = (1 - momentum) * running + momentum * batch`.
+/// Otherwise `x` is normalized with the running statistics, which are left unchanged.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let (mut mean, mut var) = (0f32, 1.);
+///
+/// let mut x = [1f32, 3.];
+/// batch_norm_running(&mut x, &[1.; 2], &[0.; 2], &mut mean, &mut var, 0.5, 0., true);
+/// assert_eq!(x, [-1., 1.]);
+/// assert_eq!((mean, var), (1., 1.));
+///
+/// let mut x = [1f32, 3.];
+/// batch_norm_running(&mut x, &[1.; 2], &[0.; 2], &mut mean, &mut var, 0.5, 0., false);
+/// assert_eq!(x, [0., 2.]);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn batch_norm_running<T: Float + std::iter::Sum, const LEN: usize>(
+    x: &mut impl StaticVec<T, LEN>,
+    gamma: &impl StaticVec<T, LEN>,
+    beta: &impl StaticVec<T, LEN>,
+    running_mean: &mut T,
+    running_var: &mut T,
+    momentum: T,
+    eps: T,
+    training: bool,
+) {
+    let (mean, var) = if training {
+        let (mean, var) = moments(x.moo_ref());
+        *running_mean = (T::_1 - momentum) * *running_mean + momentum * mean;
+        *running_var = (T::_1 - momentum) * *running_var + momentum * var;
+        (mean, var)
+    } else {
+        (*running_mean, *running_var)
+    };
+    normalize_affine(x, gamma, beta, mean, var, eps);
+}
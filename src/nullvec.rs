@@ -11,6 +11,17 @@ use std::marker::PhantomData;
 /// use slas::prelude::*;
 ///
 /// unsafe{ NullVec::<f32, 10>::new() };
+///
+/// // Creating a NullVec is not actually unsafe, as it contains no data.
+/// let a = NullVec::<f32, 10>::default();
+/// let b = a;
+/// let _ = (a, b.clone());
+///
+/// // Reshaping only wraps the NullVec, so it can be used as a placeholder tensor or matrix.
+/// let t = NullVec::<f32, 6>::default().reshape([3, 2], slas_backend::Rust);
+/// assert_eq!(t.shape, [3, 2]);
+/// let m = NullVec::<f32, 6>::default().matrix::<slas_backend::Rust, 2, 3>();
+/// assert_eq!(m.rows(), 2);
 /// ```
 #[derive(Debug)]
 pub struct NullVec<T, const LEN: usize>(PhantomData<T>);
@@ -21,6 +32,21 @@ impl<T, const LEN: usize> NullVec<T, LEN> {
     }
 }
 
+impl<T, const LEN: usize> Default for NullVec<T, LEN> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+// Implemented manually, as deriving would require `T: Clone`.
+impl<T, const LEN: usize> Clone for NullVec<T, LEN> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const LEN: usize> Copy for NullVec<T, LEN> {}
+
 macro_rules! impl_null_vec {
     ($({$name: ident, ($($args: expr),*)=> $($body: tt)* }),*) => {
         impl<T, const LEN: usize> StaticVec<T, LEN> for NullVec<T, LEN> {$(
@@ -45,6 +71,7 @@ macro_rules! impl_null_vec {
     };
 }
 
+// `reshape` and `matrix` are not overridden, as they only wrap self without accessing any data.
 impl_null_vec! {
     {as_ptr, () => unsafe fn as_ptr(&self) -> *const T},
     {as_mut_ptr, () => unsafe fn as_mut_ptr(&mut self) -> *mut T},
@@ -61,6 +88,24 @@ fn create_null_vec() {
     unsafe { NullVec::<f32, 10>::new() };
 }
 
+#[test]
+#[allow(clippy::clone_on_copy)]
+fn default_null_vec() {
+    let a = NullVec::<f32, 10>::default();
+    let _copy = a;
+    let _clone = a.clone();
+}
+
+#[test]
+fn reshape_null_vec() {
+    let t = NullVec::<f32, 6>::default().reshape([2, 3], crate::backends::Rust);
+    assert_eq!(t.shape, [2, 3]);
+
+    let m = NullVec::<f32, 6>::default().matrix::<crate::backends::Rust, 3, 2>();
+    assert_eq!(m.rows(), 3);
+    assert_eq!(m.columns(), 2);
+}
+
 #[test]
 #[should_panic]
 fn mutation() {
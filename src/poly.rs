@@ -0,0 +1,50 @@
+//! Polynomial evaluation.
+//!
+//! Coefficients are stored in ascending order of degree,
+//! so `[c0, c1, c2]` is the polynomial `c0 + c1 * x + c2 * x^2`.
+
+use crate::prelude::*;
+
+/// Evaluate a polynomial at `x` using Horner's method.
+///
+/// ## Example
+/// ```rust
+/// use slas::poly::polynomial_eval;
+///
+/// // 1 + 2x + 3x^2
+/// assert_eq!(polynomial_eval(&[1., 2., 3.], 2.), 17.);
+/// ```
+pub fn polynomial_eval<T: Float, const N: usize>(coeffs: &impl StaticVec<T, N>, x: T) -> T {
+    let mut acc = T::_0;
+    for n in (0..N).rev() {
+        acc = acc * x + unsafe { *coeffs.get_unchecked(n) };
+    }
+    acc
+}
+
+/// Evaluate a polynomial at `M` points at once, using Horner's method.
+///
+/// All points are updated for each coefficient,
+/// so the inner loop is independent between points and can be vectorized by the compiler.
+///
+/// ## Example
+/// ```rust
+/// use slas::poly::polynomial_eval_batch;
+///
+/// // 1 + 2x + 3x^2
+/// assert_eq!(polynomial_eval_batch(&[1., 2., 3.], &[0., 1., 2.]), [1., 6., 17.]);
+/// ```
+pub fn polynomial_eval_batch<T: Float, const N: usize, const M: usize>(
+    coeffs: &impl StaticVec<T, N>,
+    xs: &impl StaticVec<T, M>,
+) -> [T; M] {
+    let xs = xs.moo_ref();
+    let mut acc = [T::_0; M];
+    for n in (0..N).rev() {
+        let c = unsafe { *coeffs.get_unchecked(n) };
+        for m in 0..M {
+            acc[m] = acc[m] * xs[m] + c;
+        }
+    }
+    acc
+}
@@ -1,5 +1,24 @@
 pub use crate::{
-    backends as slas_backend, backends::Backend, dynamic_vec::*, m, moo, num::Complex, num::Float,
-    num::*, static_vec::*, tensor::Matrix, tensor::MatrixShape, tensor::Tensor, MutStaticVecRef,
-    NullVec, StaticCowVec, StaticVecRef, StaticVecUnion,
+    backends as slas_backend,
+    backends::{weighted_dot, Backend},
+    complex::{ComplexExt, TranscendentalFloat},
+    distance::{cosine_similarity, euclidean_distance},
+    dual::Dual,
+    dynamic_vec::*,
+    interpolate::{lerp, lerp_in_place, slerp},
+    m,
+    ml::{
+        batch_norm_inplace, batch_norm_running, binary_cross_entropy, cross_entropy, kl_divergence,
+    },
+    moo,
+    num::Complex,
+    num::Float,
+    num::*,
+    static_vec::*,
+    stats::WelfordAccumulator,
+    tensor::Matrix,
+    tensor::MatrixShape,
+    tensor::Tensor,
+    MutStaticVecRef, NullVec, SliceLengthError, StaticCowVec, StaticVecRef, StaticVecUnion,
+    UnitVec,
 };
@@ -0,0 +1,176 @@
+//! Profile-guided tuning of the `SLAS_*` build-time thresholds.
+//! Only available with the `profile` feature.
+//!
+//! While a function annotated with [`#[slas::profile]`](macro@crate::profile) is running,
+//! every operation that chooses between two implementations based on a threshold from [`crate::config`],
+//! runs both implementations and records how long each of them took.
+//! After every `runs` calls to the function, the suggested values for the thresholds are printed to stderr.
+//!
+//! Running both implementations makes profiled code slower, so profiling should only be enabled while tuning.
+//!
+//! ## Example
+//! ```rust
+//! use slas::prelude::*;
+//!
+//! #[slas::profile(runs = 2)]
+//! fn step() -> f32 {
+//!     let a = moo![f32: 0..1000];
+//!     a.dot(&a)
+//! }
+//!
+//! step();
+//! step(); // Prints `slas profile of rust_out::step after 2 runs: SLAS_BLAS_IN_DOT_IF_LEN_GE=...`
+//! assert!(slas::profile::suggest(slas::profile::Knob::BlasInDot).is_some());
+//! ```
+
+use crate::config;
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Thresholds that can be tuned by profiling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Knob {
+    /// [`config::BLAS_IN_DOT_IF_LEN_GE`]
+    BlasInDot,
+    /// [`config::BLAS_IN_GEMM_IF_SIZE_GE`]
+    BlasInGemm,
+    /// [`config::SIMD_IN_ADD_IF_LEN_GE`]
+    SimdInAdd,
+}
+
+impl Knob {
+    pub const ALL: [Knob; 3] = [Knob::BlasInDot, Knob::BlasInGemm, Knob::SimdInAdd];
+
+    /// Name of the environment variable that sets the threshold during build.
+    pub const fn env_var(self) -> &'static str {
+        match self {
+            Knob::BlasInDot => "SLAS_BLAS_IN_DOT_IF_LEN_GE",
+            Knob::BlasInGemm => "SLAS_BLAS_IN_GEMM_IF_SIZE_GE",
+            Knob::SimdInAdd => "SLAS_SIMD_IN_ADD_IF_LEN_GE",
+        }
+    }
+
+    /// Value of the threshold that slas was built with.
+    pub const fn current(self) -> usize {
+        match self {
+            Knob::BlasInDot => config::BLAS_IN_DOT_IF_LEN_GE,
+            Knob::BlasInGemm => config::BLAS_IN_GEMM_IF_SIZE_GE,
+            Knob::SimdInAdd => config::SIMD_IN_ADD_IF_LEN_GE,
+        }
+    }
+}
+
+/// Total time and number of samples for both implementations of an operation of a given size.
+/// Index 0 is the implementation used below the threshold, and index 1 is the one used above it.
+type Samples = [(Duration, u32); 2];
+
+// `BTreeMap::new` is not const on stable, so the maps are created on first use.
+static SAMPLES: Mutex<Option<BTreeMap<(Knob, usize), Samples>>> = Mutex::new(None);
+static RUNS: Mutex<Option<BTreeMap<&'static str, u64>>> = Mutex::new(None);
+
+thread_local! {
+    static DEPTH: Cell<usize> = Cell::new(0);
+}
+
+/// Returns true if called (directly or indirectly) from a profiled function on the current thread.
+pub fn is_active() -> bool {
+    DEPTH.with(|d| d.get() > 0)
+}
+
+/// Run both implementations of an operation of size `size`, and record how long each of them took.
+/// `run(true)` should run the implementation used above the threshold, and `run(false)` the one used below it.
+///
+/// Returns the result of `run(above)`.
+pub fn compare<R>(knob: Knob, size: usize, above: bool, mut run: impl FnMut(bool) -> R) -> R {
+    let mut elapsed = [Duration::ZERO; 2];
+    let mut result = None;
+
+    // The implementation that would normally be chosen runs last,
+    // so its result is the one that is returned (and left in any output buffers).
+    for n in [!above, above] {
+        let start = Instant::now();
+        result = Some(run(n));
+        elapsed[n as usize] = start.elapsed();
+    }
+
+    let mut samples = SAMPLES.lock().unwrap();
+    let samples = samples
+        .get_or_insert_with(BTreeMap::new)
+        .entry((knob, size))
+        .or_insert([(Duration::ZERO, 0); 2]);
+    for (s, e) in samples.iter_mut().zip(elapsed) {
+        s.0 += e;
+        s.1 += 1;
+    }
+
+    result.unwrap()
+}
+
+/// Suggested value for `knob`, based on the samples recorded so far.
+/// This is the smallest recorded size, where the implementation used above the threshold
+/// was faster on average, for that size and all larger sizes.
+///
+/// Returns `None` if no samples have been recorded for `knob`.
+pub fn suggest(knob: Knob) -> Option<usize> {
+    let samples = SAMPLES.lock().unwrap();
+    let samples = samples.as_ref()?;
+    let avg = |(total, count): (Duration, u32)| total / count;
+
+    let mut suggestion = None;
+    for (&(_, size), s) in samples.range((knob, 0)..=(knob, usize::MAX)).rev() {
+        if avg(s[1]) < avg(s[0]) {
+            suggestion = Some(size);
+        } else {
+            return Some(suggestion.unwrap_or(size + 1));
+        }
+    }
+    suggestion
+}
+
+/// Print the suggested values of all knobs with recorded samples to stderr.
+pub fn report(name: &str, runs: u64) {
+    for knob in Knob::ALL {
+        if let Some(value) = suggest(knob) {
+            eprintln!(
+                "slas profile of {name} after {runs} runs: {}={value} (currently {})",
+                knob.env_var(),
+                knob.current()
+            );
+        }
+    }
+}
+
+/// Marks the current thread as profiling until dropped.
+/// Created at the start of functions annotated with [`#[slas::profile]`](macro@crate::profile).
+pub struct Guard {
+    name: &'static str,
+    report_every: u64,
+}
+
+impl Guard {
+    pub fn enter(name: &'static str, report_every: u64) -> Self {
+        DEPTH.with(|d| d.set(d.get() + 1));
+        Self { name, report_every }
+    }
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        DEPTH.with(|d| d.set(d.get() - 1));
+
+        let runs = {
+            let mut runs = RUNS.lock().unwrap();
+            let runs = runs
+                .get_or_insert_with(BTreeMap::new)
+                .entry(self.name)
+                .or_insert(0);
+            *runs += 1;
+            *runs
+        };
+        if runs % self.report_every == 0 {
+            report(self.name, runs);
+        }
+    }
+}
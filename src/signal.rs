@@ -0,0 +1,125 @@
+//! Signal processing transforms and filters.
+
+use crate::prelude::*;
+
+/// Fails compilation when `LEN` is not a power of two.
+struct AssertPowerOfTwo<const LEN: usize>;
+
+impl<const LEN: usize> AssertPowerOfTwo<LEN> {
+    const OK: () = assert!(LEN.is_power_of_two(), "Length must be a power of two");
+}
+
+/// In place, unnormalized fast Walsh-Hadamard transform.
+/// Applying the transform twice scales the input by `LEN`.
+///
+/// `LEN` must be a power of two, which is checked at compile time.
+///
+/// ## Example
+/// ```rust
+/// use slas::signal::fwht_inplace;
+///
+/// let mut v = [1., 0., 1., 0.];
+/// fwht_inplace(&mut v);
+/// assert_eq!(v, [2., 2., 0., 0.]);
+/// ```
+pub fn fwht_inplace<T: Float, const LEN: usize>(v: &mut impl StaticVec<T, LEN>) {
+    #[allow(clippy::let_unit_value)]
+    let _ = AssertPowerOfTwo::<LEN>::OK;
+
+    let v = v.mut_moo_ref();
+    let mut h = 1;
+    while h < LEN {
+        for start in (0..LEN).step_by(h * 2) {
+            // The inner loop is over contiguous elements, so it can be vectorized by the compiler.
+            for n in start..start + h {
+                let a = v[n];
+                let b = v[n + h];
+                v[n] = a + b;
+                v[n + h] = a - b;
+            }
+        }
+        h *= 2;
+    }
+}
+
+/// Same as [`fwht_inplace`], but returns the result in a new array.
+///
+/// ## Example
+/// ```rust
+/// use slas::signal::fwht;
+///
+/// assert_eq!(fwht(&[1., 0., 0., 0.]), [1.; 4]);
+/// ```
+pub fn fwht<T: Float, const LEN: usize>(v: &impl StaticVec<T, LEN>) -> [T; LEN] {
+    let mut out = **v.moo_ref();
+    fwht_inplace(&mut out);
+    out
+}
+
+impl<'a, T: Float, const LEN: usize> StaticVecUnion<'a, T, LEN>
+where
+    crate::backends::Rust: crate::backends::operations::DotProduct<T, DotOutput = T>,
+{
+    /// Element `n` of the full convolution of self with `kernel`.
+    fn convolve_at<const KLEN: usize>(&self, kernel: &[T; KLEN], n: usize) -> T {
+        let mut sum = T::_0;
+        for k in n.saturating_sub(LEN - 1)..KLEN.min(n + 1) {
+            sum += self[n - k] * kernel[k];
+        }
+        sum
+    }
+
+    /// Convolution of self with `kernel`, only where the kernel fully overlaps self.
+    /// Does not compile if `KLEN > LEN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!(moo![f32: 1, 2, 3, 4].convolve_valid(&[1., 0., -1.]), [2., 2.]);
+    /// ```
+    pub fn convolve_valid<const KLEN: usize>(
+        &self,
+        kernel: &impl StaticVec<T, KLEN>,
+    ) -> [T; LEN - KLEN + 1]
+    where
+        [(); LEN - KLEN + 1]:,
+    {
+        let mut reversed = **kernel.moo_ref();
+        reversed.reverse();
+        self.correlate(&reversed)
+    }
+
+    /// Convolution of self with `kernel`, with the same length as self.
+    /// This is the center part of [`Self::convolve_full`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!(moo![f32: 1, 2, 3, 4].convolve_same(&[1., 1., 1.]), [3., 6., 9., 7.]);
+    /// ```
+    pub fn convolve_same<const KLEN: usize>(&self, kernel: &impl StaticVec<T, KLEN>) -> [T; LEN] {
+        let kernel = **kernel.moo_ref();
+        std::array::from_fn(|n| self.convolve_at(&kernel, n + (KLEN - 1) / 2))
+    }
+
+    /// Full convolution of self with `kernel`, including the parts where they only partially overlap.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!(moo![f32: 1, 2, 3].convolve_full(&[1., 1.]), [1., 3., 5., 3.]);
+    /// ```
+    pub fn convolve_full<const KLEN: usize>(
+        &self,
+        kernel: &impl StaticVec<T, KLEN>,
+    ) -> [T; LEN + KLEN - 1]
+    where
+        [(); LEN + KLEN - 1]:,
+    {
+        let kernel = **kernel.moo_ref();
+        std::array::from_fn(|n| self.convolve_at(&kernel, n))
+    }
+}
@@ -1,3 +1,11 @@
+//! Number of SIMD lanes available on the architecture compiled on.
+//!
+//! The rust backend uses [`std::simd`], which compiles to SSE, AVX, ARM NEON or WebAssembly SIMD instructions,
+//! so only the lane counts depend on the target.
+//!
+//! WebAssembly SIMD (128-bit) is used when compiling for `wasm32` with the `simd128` target feature enabled,
+//! fx. with `RUSTFLAGS="-C target-feature=+simd128"`.
+
 use std::mem::size_of;
 
 const LANES_8: bool = cfg!(any(
@@ -14,11 +22,14 @@ const LANES_4: bool = cfg!(any(
     target_feature = "sse4.1",
     target_feature = "sse4.2",
     target_feature = "sse4a",
+    target_feature = "neon",
+    target_feature = "simd128",
 ));
 
 const LANES_16: bool = cfg!(target_feature = "avx512");
 
 /// Available SIMD lanes on CPU architecutre compiled on for the f32 type.
+/// SSE, ARM NEON and WebAssembly SIMD have 4 lanes, AVX has 8 and AVX-512 has 16.
 /// So if you want to make a SIMD vector of f64 you would want to use LANES/2.
 /// Will be 0 if SIMD is not available.
 pub const MAX: usize = if LANES_16 {
@@ -31,12 +42,70 @@ pub const MAX: usize = if LANES_16 {
     0
 };
 
+/// Width of the SIMD registers on the architecture compiled on, in bytes.
+/// Will be 0 if SIMD is not available.
+pub const REGISTER_BYTES: usize = MAX * size_of::<f32>();
+
 /// Returns the number of lanes, for a given type, that can fit into a SIMD vektor on the architecture compiled on.
+/// This works for all primitive numbers, including integers smaller than f32, like `u8` and `i16`.
 pub const fn max_for_type<T>() -> usize {
-    MAX / (size_of::<T>() / size_of::<f32>())
+    REGISTER_BYTES / size_of::<T>()
 }
 
+// Lane counts are checked at compile time for the architecture compiled on.
+const _: () = assert!(max_for_type::<f32>() == MAX);
+const _: () = assert!(max_for_type::<i32>() == MAX);
+const _: () = assert!(max_for_type::<u32>() == MAX);
+const _: () = assert!(max_for_type::<f64>() == MAX / 2);
+const _: () = assert!(max_for_type::<i64>() == MAX / 2);
+const _: () = assert!(max_for_type::<u64>() == MAX / 2);
+const _: () = assert!(max_for_type::<i16>() == MAX * 2);
+const _: () = assert!(max_for_type::<u8>() == MAX * 4);
+
 #[test]
 fn lanes() {
     max_for_type::<f32>();
 }
+
+#[test]
+#[cfg(all(target_feature = "sse", not(target_feature = "avx")))]
+fn sse_lanes() {
+    assert_eq!(max_for_type::<u8>(), 16);
+    assert_eq!(max_for_type::<i16>(), 8);
+    assert_eq!(max_for_type::<i32>(), 4);
+    assert_eq!(max_for_type::<u64>(), 2);
+}
+
+#[test]
+#[cfg(all(target_feature = "avx", not(target_feature = "avx512")))]
+fn avx_lanes() {
+    assert_eq!(max_for_type::<u8>(), 32);
+    assert_eq!(max_for_type::<i16>(), 16);
+    assert_eq!(max_for_type::<i32>(), 8);
+    assert_eq!(max_for_type::<u64>(), 4);
+}
+
+#[test]
+#[cfg(target_feature = "avx512")]
+fn avx512_lanes() {
+    assert_eq!(max_for_type::<u8>(), 64);
+    assert_eq!(max_for_type::<i16>(), 32);
+    assert_eq!(max_for_type::<i32>(), 16);
+    assert_eq!(max_for_type::<u64>(), 8);
+}
+
+#[test]
+#[cfg(target_feature = "neon")]
+fn neon_lanes() {
+    assert_eq!(max_for_type::<f32>(), 4);
+    assert_eq!(max_for_type::<f64>(), 2);
+    assert_eq!(max_for_type::<u8>(), 16);
+}
+
+#[test]
+#[cfg(target_feature = "simd128")]
+fn wasm_simd_lanes() {
+    assert_eq!(max_for_type::<f32>(), 4);
+    assert_eq!(max_for_type::<f64>(), 2);
+    assert_eq!(max_for_type::<u8>(), 16);
+}
@@ -0,0 +1,105 @@
+//! Iterative solvers for linear systems.
+
+use crate::backends::operations;
+use crate::prelude::*;
+
+/// Solve `a * x = b` for `x`, where `a` is a symmetric positive definite matrix,
+/// using the conjugate gradient method.
+///
+/// Returns the solution and the number of iterations used.
+/// Stops when `||r|| < tol * ||b||`, where `r = b - a * x` is the residual,
+/// or after `MAX_ITER` iterations, in which case the returned solution has not converged.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::solvers::cg_solve;
+///
+/// let a = moo![f64: 4, 1, 1, 3].matrix::<slas_backend::Rust, 2, 2>();
+/// let (x, iters) = cg_solve::<f64, _, _, 2, 4, 10>(&a, &[1., 2.], 1e-10);
+///
+/// assert!(iters <= 2);
+/// assert!((x[0] - 1. / 11.).abs() < 1e-10);
+/// assert!((x[1] - 7. / 11.).abs() < 1e-10);
+/// ```
+pub fn cg_solve<
+    T: Float + PartialOrd,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>
+        + operations::MatrixVectorMul<T>
+        + operations::DotProduct<T, DotOutput = T>
+        + operations::Axpy<T>,
+    const N: usize,
+    const LEN: usize,
+    const MAX_ITER: usize,
+>(
+    a: &Matrix<T, U, B, LEN, false, MatrixShape<N, N>>,
+    b: &impl StaticVec<T, N>,
+    tol: T,
+) -> ([T; N], usize) {
+    pcg_solve::<T, U, B, N, LEN, MAX_ITER>(a, b, tol, |r| *r)
+}
+
+/// Same as [`cg_solve`], but preconditioned by `m_inv`,
+/// which should apply an approximation of the inverse of `a` to the residual.
+/// `m_inv` must be symmetric positive definite as well.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::solvers::pcg_solve;
+///
+/// let a = moo![f64: 4, 1, 1, 3].matrix::<slas_backend::Rust, 2, 2>();
+/// // Jacobi preconditioner.
+/// let (x, _) = pcg_solve::<f64, _, _, 2, 4, 10>(&a, &[1., 2.], 1e-10, |r| [r[0] / 4., r[1] / 3.]);
+///
+/// assert!((x[0] - 1. / 11.).abs() < 1e-10);
+/// assert!((x[1] - 7. / 11.).abs() < 1e-10);
+/// ```
+pub fn pcg_solve<
+    T: Float + PartialOrd,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>
+        + operations::MatrixVectorMul<T>
+        + operations::DotProduct<T, DotOutput = T>
+        + operations::Axpy<T>,
+    const N: usize,
+    const LEN: usize,
+    const MAX_ITER: usize,
+>(
+    a: &Matrix<T, U, B, LEN, false, MatrixShape<N, N>>,
+    b: &impl StaticVec<T, N>,
+    tol: T,
+    m_inv: impl Fn(&[T; N]) -> [T; N],
+) -> ([T; N], usize) {
+    let backend = a.backend();
+    let dot = |x: &[T; N], y: &[T; N]| operations::DotProduct::<T>::dot(backend, x, y);
+
+    let mut x = [T::_0; N];
+    let mut r = **b.moo_ref();
+    let threshold = tol * tol * dot(&r, &r);
+
+    let mut z = m_inv(&r);
+    let mut p = z;
+    let mut rz = dot(&r, &z);
+
+    for iter in 0..MAX_ITER {
+        if dot(&r, &r) <= threshold {
+            return (x, iter);
+        }
+
+        let ap: [T; N] = a.vector_mul(&p);
+        let alpha = rz / dot(&p, &ap);
+        operations::Axpy::<T>::axpy(backend, alpha, &p, &mut x);
+        operations::Axpy::<T>::axpy(backend, T::_0 - alpha, &ap, &mut r);
+
+        z = m_inv(&r);
+        let next_rz = dot(&r, &z);
+        let beta = next_rz / rz;
+        rz = next_rz;
+        for n in 0..N {
+            p[n] = z[n] + beta * p[n];
+        }
+    }
+    (x, MAX_ITER)
+}
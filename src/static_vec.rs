@@ -2,11 +2,22 @@ use crate::prelude::*;
 use crate::StaticVecUnion;
 use paste::paste;
 use std::{
+    convert::TryInto,
     marker::PhantomData,
     mem::{transmute, transmute_copy},
     ops::DerefMut,
 };
 
+/// Compile time assertion that a vector of `LEN` elements can be split into chunks of `CHUNK` elements.
+struct AssertChunks<const LEN: usize, const CHUNK: usize>;
+
+impl<const LEN: usize, const CHUNK: usize> AssertChunks<LEN, CHUNK> {
+    const OK: () = assert!(
+        CHUNK != 0 && LEN % CHUNK == 0,
+        "The length of the vector must be a multiple of the chunk size"
+    );
+}
+
 /// A very general trait for anything that can be called a static vector (fx. `[T; LEN]`)
 ///
 /// **Warning:** If self is not contiguous, it will cause undefined behaviour.
@@ -121,6 +132,9 @@ macro_rules! impl_reshape {
 pub trait StaticVec<T, const LEN: usize> {
     /// Return pointer to first element.
     ///
+    /// The trait method can't be called in const contexts, but [`StaticVecUnion::as_ptr`] and [`StaticCowVec::as_ptr`] can.
+    /// Arrays can be borrowed with the const [`StaticCowVec::from_ref`] to get their pointer in const contexts.
+    ///
     /// # Safety
     /// Is safe as long as `self` is contiguous.
     unsafe fn as_ptr(&self) -> *const T;
@@ -207,6 +221,282 @@ pub trait StaticVec<T, const LEN: usize> {
         unsafe { transmute_copy(self) }
     }
 
+    /// Cumulative sum (prefix sum) of the elements of self.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!(*[1., 2., 3., 4.].cumsum(), [1., 3., 6., 10.]);
+    /// ```
+    fn cumsum(&self) -> StaticVecUnion<'static, T, LEN>
+    where
+        T: Copy + std::ops::Add<Output = T>,
+    {
+        let mut out = StaticVecUnion {
+            owned: **self.moo_ref(),
+        };
+        for n in 1..LEN {
+            out[n] = out[n - 1] + out[n];
+        }
+        out
+    }
+
+    /// Element-wise clamp of self to the range `[lo, hi]`.
+    /// Not called `clamp`, as that would be shadowed by [`Ord::clamp`] for arrays of integers.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!(*[-2., 0.5, 3.].clamp_elements(0., 1.), [0., 0.5, 1.]);
+    /// assert_eq!(*[1, 5, -2].clamp_elements(0, 3), [1, 3, 0]);
+    /// ```
+    fn clamp_elements(&self, lo: T, hi: T) -> StaticVecUnion<'static, T, LEN>
+    where
+        T: PartialOrd + Copy,
+    {
+        let mut out = StaticVecUnion {
+            owned: **self.moo_ref(),
+        };
+        self.clamp_elements_into(lo, hi, out.mut_moo_ref());
+        out
+    }
+
+    /// Element-wise clamp of self to the range `[lo, hi]`, written into `out`.
+    fn clamp_elements_into(&self, lo: T, hi: T, out: MutStaticVecRef<T, LEN>)
+    where
+        T: PartialOrd + Copy,
+    {
+        for n in 0..LEN {
+            let x = unsafe { *self.get_unchecked(n) };
+            out[n] = if x < lo {
+                lo
+            } else if x > hi {
+                hi
+            } else {
+                x
+            };
+        }
+    }
+
+    /// Apply `f` to every window of `WLEN` consecutive elements in self.
+    /// The windows are references into self, so no data is copied.
+    ///
+    /// There is no simd path, as `f` can be any function.
+    /// [`Self::correlate`] uses the simd dot product of the [`crate::backends::Rust`] backend for each window.
+    ///
+    /// ## Example
+    /// ```rust
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    /// use slas::prelude::*;
+    ///
+    /// let sums = [1f32, 2., 3., 4.].windows_apply(|w: &[f32; 2]| w[0] + w[1]);
+    /// assert_eq!(sums, [3., 5., 7.]);
+    /// ```
+    fn windows_apply<const WLEN: usize, U, F: Fn(&[T; WLEN]) -> U>(
+        &self,
+        f: F,
+    ) -> [U; LEN - WLEN + 1]
+    where
+        [(); LEN - WLEN + 1]:,
+    {
+        std::array::from_fn(|n| f(unsafe { self.static_slice_unchecked::<WLEN>(n) }))
+    }
+
+    /// Cross-correlation of self with `kernel` (no padding),
+    /// computed as the dot product of the kernel with every window of self on the [`crate::backends::Rust`] backend.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!([1f32, 2., 3., 4.].correlate(&[1., -1.]), [-1., -1., -1.]);
+    /// ```
+    fn correlate<const KLEN: usize>(&self, kernel: &impl StaticVec<T, KLEN>) -> [T; LEN - KLEN + 1]
+    where
+        [(); LEN - KLEN + 1]:,
+        crate::backends::Rust: crate::backends::operations::DotProduct<T, DotOutput = T>,
+    {
+        self.windows_apply(|w: &[T; KLEN]| {
+            crate::backends::operations::DotProduct::dot(&crate::backends::Rust, w, kernel)
+        })
+    }
+
+    /// Index of the largest element. Returns the first index if there are multiple largest elements.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!([1., 5., 2., 5.].argmax(), 1);
+    /// ```
+    fn argmax(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.argmax_by(|a, b| a > b)
+    }
+
+    /// Index of the smallest element. Returns the first index if there are multiple smallest elements.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!([3., -1., 2., -1.].argmin(), 1);
+    /// ```
+    fn argmin(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        self.argmax_by(|a, b| a < b)
+    }
+
+    /// Index of the first element `x`, for which `better(x, y)` is false for all other elements `y`.
+    #[doc(hidden)]
+    fn argmax_by(&self, better: impl Fn(&T, &T) -> bool) -> usize {
+        assert!(LEN > 0, "Cannot find extremal element of empty vector");
+        let mut best = 0;
+        let mut n = 1;
+        unsafe {
+            while n + 4 <= LEN {
+                for i in n..n + 4 {
+                    if better(self.get_unchecked(i), self.get_unchecked(best)) {
+                        best = i;
+                    }
+                }
+                n += 4;
+            }
+            for i in n..LEN {
+                if better(self.get_unchecked(i), self.get_unchecked(best)) {
+                    best = i;
+                }
+            }
+        }
+        best
+    }
+
+    /// Iterate over non-overlapping chunks of `CHUNK` elements.
+    /// The chunks are references into self, so no data is copied.
+    ///
+    /// Fails to compile if `LEN` is not a multiple of `CHUNK`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let sums: Vec<f32> = moo![f32: 0..6].chunks::<2>().map(|c| c[0] + c[1]).collect();
+    /// assert_eq!(sums, [1., 5., 9.]);
+    /// ```
+    fn chunks<const CHUNK: usize>(&self) -> std::slice::Iter<'_, [T; CHUNK]> {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertChunks::<LEN, CHUNK>::OK;
+        unsafe { std::slice::from_raw_parts(self.static_slice_unchecked::<CHUNK>(0), LEN / CHUNK) }
+            .iter()
+    }
+
+    /// Split self into the first `AT` elements and the remaining `LEN - AT` elements.
+    /// The halves are references into self, so no data is copied.
+    ///
+    /// Fails to compile if `AT > LEN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let (a, b) = [1f32, 2., 3., 4., 5.].split_at::<2>();
+    /// assert_eq!(a, &[1., 2.]);
+    /// assert_eq!(b, &[3., 4., 5.]);
+    /// ```
+    fn split_at<const AT: usize>(&self) -> (&[T; AT], &[T; LEN - AT])
+    where
+        [(); LEN - AT]:,
+    {
+        unsafe {
+            (
+                self.static_slice_unchecked::<AT>(0),
+                self.static_slice_unchecked::<{ LEN - AT }>(AT),
+            )
+        }
+    }
+
+    /// Same as [`Self::split_at`] but mutable.
+    /// Writes go through [`Self::mut_moo_ref`], so cow behavior is perserved.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let mut v = [1., 2., 3., 4., 5.];
+    /// let (a, b) = v.split_at_mut::<2>();
+    /// a[0] = b[2];
+    /// assert_eq!(v, [5., 2., 3., 4., 5.]);
+    /// ```
+    fn split_at_mut<const AT: usize>(&mut self) -> (&mut [T; AT], &mut [T; LEN - AT])
+    where
+        [(); LEN - AT]:,
+        T: Copy,
+    {
+        let (a, b) = <[T]>::split_at_mut(&mut **self.mut_moo_ref(), AT);
+        (a.try_into().unwrap(), b.try_into().unwrap())
+    }
+
+    /// Cyclic shift of self `K` positions to the left,
+    /// so the first `K` elements wrap around to the end.
+    ///
+    /// Fails to compile if `K > LEN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!(*[1., 2., 3., 4., 5.].rotate_left::<2>(), [3., 4., 5., 1., 2.]);
+    /// ```
+    fn rotate_left<const K: usize>(&self) -> StaticVecUnion<'static, T, LEN>
+    where
+        [(); LEN - K]:,
+        T: Copy,
+    {
+        let mut out = StaticVecUnion {
+            owned: **self.moo_ref(),
+        };
+        unsafe {
+            *out.mut_static_slice_unchecked::<{ LEN - K }>(0) =
+                *self.static_slice_unchecked::<{ LEN - K }>(K);
+            *out.mut_static_slice_unchecked::<K>(LEN - K) = *self.static_slice_unchecked::<K>(0);
+        }
+        out
+    }
+
+    /// Cyclic shift of self `K` positions to the right,
+    /// so the last `K` elements wrap around to the start.
+    ///
+    /// Fails to compile if `K > LEN`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// assert_eq!(*[1., 2., 3., 4., 5.].rotate_right::<2>(), [4., 5., 1., 2., 3.]);
+    /// ```
+    fn rotate_right<const K: usize>(&self) -> StaticVecUnion<'static, T, LEN>
+    where
+        [(); LEN - K]:,
+        T: Copy,
+    {
+        let mut out = StaticVecUnion {
+            owned: **self.moo_ref(),
+        };
+        unsafe {
+            *out.mut_static_slice_unchecked::<K>(0) = *self.static_slice_unchecked::<K>(LEN - K);
+            *out.mut_static_slice_unchecked::<{ LEN - K }>(K) =
+                *self.static_slice_unchecked::<{ LEN - K }>(0);
+        }
+        out
+    }
+
     /// Statically use `B` as a backend for self.
     fn static_backend<B: Backend<T> + Default>(
         self,
@@ -233,7 +523,7 @@ impl<'a, T: Copy, const LEN: usize> StaticVecUnion<'a, T, LEN> {
 
 impl<'a, T: Copy, const LEN: usize> StaticVec<T, LEN> for StaticVecUnion<'a, T, LEN> {
     unsafe fn as_ptr(&self) -> *const T {
-        self.owned.as_ptr()
+        StaticVecUnion::as_ptr(self)
     }
 }
 
@@ -293,11 +583,7 @@ impl_vec_for_refs!(&mut &mut);
 
 impl<'a, T: Copy, const LEN: usize> StaticVec<T, LEN> for StaticCowVec<'a, T, LEN> {
     unsafe fn as_ptr(&self) -> *const T {
-        if self.is_owned {
-            self.data.as_ptr()
-        } else {
-            self.data.borrowed as *const T
-        }
+        StaticCowVec::as_ptr(self)
     }
 
     /// For [`StaticCowVec`] calling `as_mut_ptr` will dereference self and thereby copy the contents of self.borrowed into self, if self is borrowed.
@@ -317,3 +603,59 @@ impl<'a, T: Copy, const LEN: usize> StaticVec<T, LEN> for StaticCowVec<'a, T, LE
         unsafe { transmute(self.deref_mut()) }
     }
 }
+
+/// Collect the elements of `source` at `indices`, so that `gather(source, indices)[n] == source[indices[n]]`.
+/// Panics if an index is out of bounds.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// assert_eq!(gather(&[10, 20, 30, 40], &[3, 0, 3]), [40, 10, 40]);
+/// ```
+pub fn gather<T: Copy, const N: usize, const K: usize>(
+    source: &impl StaticVec<T, N>,
+    indices: &[usize; K],
+) -> [T; K] {
+    let source = source.moo_ref();
+    indices.map(|i| {
+        assert!(
+            i < N,
+            "Cannot gather index {} from vector of length {}",
+            i,
+            N
+        );
+        source[i]
+    })
+}
+
+/// Write the elements of `values` to `dest` at `indices`, so that `dest[indices[n]] = values[n]`.
+/// If an index occurs more than once, the last value written to it is kept.
+/// Panics if an index is out of bounds, in which case `dest` is left unchanged.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let mut dest = [0; 4];
+/// scatter(&[1, 2], &[3, 1], &mut dest);
+/// assert_eq!(dest, [0, 2, 0, 1]);
+/// ```
+pub fn scatter<T: Copy, const N: usize, const K: usize>(
+    values: &impl StaticVec<T, K>,
+    indices: &[usize; K],
+    dest: &mut impl StaticVec<T, N>,
+) {
+    for &i in indices {
+        assert!(
+            i < N,
+            "Cannot scatter to index {} in vector of length {}",
+            i,
+            N
+        );
+    }
+    let dest = dest.mut_moo_ref();
+    for (v, &i) in values.moo_ref().iter().zip(indices) {
+        dest[i] = *v;
+    }
+}
@@ -0,0 +1,174 @@
+//! Statistical reductions.
+
+use crate::backends::operations;
+use crate::prelude::*;
+
+macro_rules! impl_moments {
+    ($($t: ty),*) => {$(
+        impl<'a, const LEN: usize> StaticVecUnion<'a, $t, LEN> {
+            /// Arithmetic mean of the elements.
+            ///
+            /// ## Example
+            /// ```rust
+            /// use slas::prelude::*;
+            ///
+            /// assert_eq!(moo![f32: 1, 2, 3, 6].mean(), 3.);
+            /// ```
+            pub fn mean(&self) -> $t {
+                self.iter().sum::<$t>() / LEN as $t
+            }
+
+            /// Population variance (mean of squared deviations from the mean).
+            /// The sum of squares is computed with [`StaticVecUnion::dot`].
+            ///
+            /// ## Example
+            /// ```rust
+            /// use slas::prelude::*;
+            ///
+            /// assert_eq!(moo![f32: 2, 4, 4, 4, 5, 5, 7, 9].variance(), 4.);
+            /// ```
+            pub fn variance(&self) -> $t {
+                let mean = self.mean();
+                let centered = StaticVecUnion {
+                    owned: std::array::from_fn::<$t, LEN, _>(|n| self[n] - mean),
+                };
+                centered.dot(&centered) / LEN as $t
+            }
+
+            /// Population standard deviation, `variance().sqrt_()`.
+            pub fn std_dev(&self) -> $t {
+                self.variance().sqrt_()
+            }
+        }
+    )*};
+}
+
+impl_moments!(f32, f64);
+
+macro_rules! impl_covariance {
+    ($($t: ty),*) => {$(
+        impl<
+                U: StaticVec<$t, LEN>,
+                B: Backend<$t> + operations::MatrixMul<$t>,
+                const LEN: usize,
+                const N: usize,
+                const D: usize,
+            > Matrix<$t, U, B, LEN, false, MatrixShape<N, D>>
+        {
+            /// Sample covariance matrix of `self`, where rows are observations and columns are features.
+            ///
+            /// Each column is centred around its mean, and the covariance is computed as `X^T X / (N - 1)`
+            /// with [`Matrix::matrix_mul`].
+            ///
+            /// ## Example
+            /// ```rust
+            /// use slas::prelude::*;
+            ///
+            /// let x = moo![f32: 1, 2, 2, 4, 3, 6].matrix::<slas_backend::Rust, 3, 2>();
+            /// assert_eq!(*x.covariance().vec_ref(), [1., 2., 2., 4.]);
+            /// ```
+            pub fn covariance(&self) -> Matrix<$t, [$t; D * D], B, { D * D }, false, MatrixShape<D, D>>
+            where
+                [(); D * D]:,
+            {
+                assert_eq!(N * D, LEN);
+                assert!(N > 1, "Cannot compute covariance of less than 2 observations");
+
+                let mut centered = [0.; LEN];
+                for c in 0..D {
+                    let mean = (0..N).map(|r| self[(r, c)]).sum::<$t>() / N as $t;
+                    for r in 0..N {
+                        centered[r * D + c] = self[(r, c)] - mean;
+                    }
+                }
+
+                let x = centered.matrix::<B, N, D>();
+                let mut cov: [$t; D * D] = x.as_transposed().matrix_mul(&x);
+                for n in cov.iter_mut() {
+                    *n /= (N - 1) as $t;
+                }
+                cov.matrix::<B, D, D>()
+            }
+        }
+    )*};
+}
+
+impl_covariance!(f32, f64);
+
+/// Running per-feature mean and variance of a stream of samples,
+/// computed with Welford's numerically stable algorithm,
+/// so the observations don't need to be stored.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let mut acc = WelfordAccumulator::<f32, 2>::new();
+/// for sample in [[1., 10.], [2., 10.], [3., 10.]] {
+///     acc.update(&sample);
+/// }
+///
+/// assert_eq!(acc.count(), 3);
+/// assert_eq!(acc.mean(), [2., 10.]);
+/// assert_eq!(acc.variance(), [2. / 3., 0.]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct WelfordAccumulator<T: Float, const N: usize> {
+    count: usize,
+    mean: [T; N],
+    m2: [T; N],
+}
+
+impl<T: Float, const N: usize> WelfordAccumulator<T, N> {
+    /// Accumulator which has not seen any samples.
+    pub const fn new() -> Self {
+        Self {
+            count: 0,
+            mean: [T::_0; N],
+            m2: [T::_0; N],
+        }
+    }
+
+    /// Number of samples seen.
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Mean of the samples seen so far.
+    pub const fn mean(&self) -> [T; N] {
+        self.mean
+    }
+}
+
+impl<T: Float, const N: usize> Default for WelfordAccumulator<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+macro_rules! impl_welford {
+    ($($t: ty),*) => {$(
+        impl<const N: usize> WelfordAccumulator<$t, N> {
+            /// Add a sample to the running statistics.
+            pub fn update(&mut self, sample: &impl StaticVec<$t, N>) {
+                self.count += 1;
+                let count = self.count as $t;
+                let sample = sample.moo_ref();
+
+                for n in 0..N {
+                    let delta = sample[n] - self.mean[n];
+                    self.mean[n] += delta / count;
+                    self.m2[n] += delta * (sample[n] - self.mean[n]);
+                }
+            }
+
+            /// Population variance of the samples seen so far.
+            /// This is NaN if no samples have been seen.
+            pub fn variance(&self) -> [$t; N] {
+                self.m2.map(|m2| m2 / self.count as $t)
+            }
+        }
+    )*};
+}
+
+impl_welford!(f32, f64);
@@ -0,0 +1,93 @@
+//! Invariants of vectors encoded in their type.
+//!
+//! A [`TaggedStaticCowVec`] wraps a [`StaticCowVec`] together with a [`TypeTag`],
+//! so functions can require an invariant in their signature, instead of checking it at runtime.
+//!
+//! ## Example
+//! ```rust
+//! use slas::prelude::*;
+//! use slas::tags::NormalizedStaticCowVec;
+//!
+//! // No need to check the norm of `axis`, as it is guaranteed to be 1.
+//! fn project(v: &[f32; 2], axis: &NormalizedStaticCowVec<f32, 2>) -> f32 {
+//!     v.moo_ref().dot(axis)
+//! }
+//!
+//! let axis = moo![f32: 3, 4].normalize();
+//! assert!((project(&[3., 4.], &axis) - 5.).abs() < 1e-6);
+//! ```
+
+use crate::backends::{operations, Rust};
+use crate::prelude::*;
+
+/// Invariants that can be tagged on a [`TaggedStaticCowVec`], as `TypeTag::... as usize`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(usize)]
+pub enum TypeTag {
+    /// No invariant is known.
+    Untagged = 0,
+    /// The vector has a norm of 1.
+    IsNormalized = 1,
+}
+
+/// A [`StaticCowVec`] tagged with the invariant `TAG`, which is a [`TypeTag`] as usize.
+///
+/// Mutable access to the underlying data is not allowed, as it could break the invariant.
+/// Use [`TaggedStaticCowVec::untag`] to get the vector back.
+#[derive(Clone, Copy)]
+pub struct TaggedStaticCowVec<'a, T: Copy, const LEN: usize, const TAG: usize>(
+    StaticCowVec<'a, T, LEN>,
+);
+
+/// A [`StaticCowVec`] that is guaranteed to have a norm of 1. Returned by [`StaticCowVec::normalize`].
+pub type NormalizedStaticCowVec<'a, T, const LEN: usize> =
+    TaggedStaticCowVec<'a, T, LEN, { TypeTag::IsNormalized as usize }>;
+
+impl<'a, T: Copy, const LEN: usize, const TAG: usize> TaggedStaticCowVec<'a, T, LEN, TAG> {
+    /// Tag `v` without checking that it satisfies the invariant.
+    ///
+    /// # Safety
+    /// Is safe as long as `v` satisfies the invariant of `TAG`.
+    pub const unsafe fn new_unchecked(v: StaticCowVec<'a, T, LEN>) -> Self {
+        Self(v)
+    }
+
+    /// Remove the tag, returning a vector that can be mutated freely.
+    pub const fn untag(self) -> StaticCowVec<'a, T, LEN> {
+        self.0
+    }
+}
+
+impl<'a, T: Float + std::iter::Sum, const LEN: usize> StaticCowVec<'a, T, LEN>
+where
+    Rust: Backend<T> + operations::Normalize<T>,
+    T: From<<Rust as operations::Normalize<T>>::NormOutput>,
+{
+    /// Normalize the vector and tag it as normalized.
+    /// The data is copied if the vector is borrowed.
+    ///
+    /// This shadows [`StaticVecUnion::normalize`] for cow vectors.
+    /// Use `v.mut_moo_ref().normalize()` to normalize in place instead.
+    pub fn normalize(mut self) -> NormalizedStaticCowVec<'a, T, LEN> {
+        operations::Normalize::normalize(&Rust, &mut self);
+        TaggedStaticCowVec(self)
+    }
+}
+
+impl<'a, T: Copy, const LEN: usize, const TAG: usize> std::ops::Deref
+    for TaggedStaticCowVec<'a, T, LEN, TAG>
+{
+    type Target = StaticVecUnion<'a, T, LEN>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'a, T: Copy + std::fmt::Debug, const LEN: usize, const TAG: usize> std::fmt::Debug
+    for TaggedStaticCowVec<'a, T, LEN, TAG>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
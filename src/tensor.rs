@@ -1,6 +1,6 @@
 use crate::{backends::*, prelude::*};
 use paste::paste;
-use std::mem::transmute;
+use std::{convert::TryInto, mem::transmute};
 
 /// Tensor shape with static dimensions but with optionally dynamic shape.
 /// To achive a static shape the trait should be const implemented.
@@ -36,6 +36,29 @@ pub trait Shape<const NDIM: usize> {
         prod
     }
 
+    /// Row-major strides of a tensor with shape.
+    /// Like in numpy, the strides are listed from the outermost axis (`NDIM - 1`) to axis 0,
+    /// so the last stride is always 1.
+    ///
+    /// Note that this is the reverse of the axis order used by [`Shape::axis_len`]:
+    /// `strides()[n]` is the stride of axis `NDIM - 1 - n`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::tensor::Shape;
+    /// assert_eq!([4, 3, 2].strides(), [12, 4, 1]);
+    /// assert_eq!(slas::tensor::MatrixShape::<2, 3>.strides(), [3, 1]);
+    /// ```
+    fn strides(&self) -> [usize; NDIM] {
+        let mut strides = [1; NDIM];
+        let mut n = 1;
+        while n < NDIM {
+            strides[NDIM - 1 - n] = strides[NDIM - n] * self.axis_len(n - 1);
+            n += 1;
+        }
+        strides
+    }
+
     fn slice(&self) -> &[usize; NDIM];
 }
 
@@ -92,9 +115,91 @@ impl Shape<2> for (usize, usize) {
 }
 
 /// Static matrix shape.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Default)]
 pub struct MatrixShape<const M: usize, const K: usize>;
 
+/// Matrix shapes with dimensions known at compile time.
+pub trait StaticMatrixShape {
+    const ROWS: usize;
+    const COLS: usize;
+}
+
+impl<const M: usize, const K: usize> StaticMatrixShape for MatrixShape<M, K> {
+    const ROWS: usize = M;
+    const COLS: usize = K;
+}
+
+/// Matrix shapes that can be multiplied by a matrix with the shape `Rhs`.
+/// Only implemented when the number of columns of `Self` matches the number of rows of `Rhs`.
+pub trait MatMul<Rhs> {
+    /// Shape of the product.
+    type Output;
+}
+
+impl<const M: usize, const K: usize, const N: usize> MatMul<MatrixShape<K, N>>
+    for MatrixShape<M, K>
+{
+    type Output = MatrixShape<M, N>;
+}
+
+/// Shape of the product of two matricies with the shapes `A` and `B`.
+///
+/// ## Example
+/// ```rust
+/// use slas::tensor::*;
+///
+/// let _: MatMulOutputShape<MatrixShape<2, 3>, MatrixShape<3, 4>> = MatrixShape::<2, 4>;
+/// ```
+pub type MatMulOutputShape<A, B> = <A as MatMul<B>>::Output;
+
+/// Returns true if a matrix with shape `a` can be multiplied by one with shape `b`.
+///
+/// ## Example
+/// ```rust
+/// use slas::tensor::*;
+///
+/// assert!(compatible_for_mul(MatrixShape::<2, 3>, MatrixShape::<3, 4>));
+/// assert!(!compatible_for_mul(MatrixShape::<2, 3>, MatrixShape::<2, 3>));
+/// ```
+pub const fn compatible_for_mul<const M: usize, const K: usize, const K2: usize, const N: usize>(
+    _a: MatrixShape<M, K>,
+    _b: MatrixShape<K2, N>,
+) -> bool {
+    K == K2
+}
+
+/// Fail compilation if two matrix shapes cannot be multiplied.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// slas::static_assert_compatible!(MatrixShape<2, 3>, MatrixShape<3, 4>);
+/// ```
+///
+/// ```compile_fail
+/// use slas::prelude::*;
+///
+/// // error: Cannot multiply MatrixShape<2, 3> by MatrixShape<2, 3>: ...
+/// slas::static_assert_compatible!(MatrixShape<2, 3>, MatrixShape<2, 3>);
+/// ```
+#[macro_export]
+macro_rules! static_assert_compatible {
+    ($a: ty, $b: ty) => {
+        const _: () = assert!(
+            <$a as $crate::tensor::StaticMatrixShape>::COLS
+                == <$b as $crate::tensor::StaticMatrixShape>::ROWS,
+            concat!(
+                "Cannot multiply ",
+                stringify!($a),
+                " by ",
+                stringify!($b),
+                ": the number of columns in the left matrix must equal the number of rows in the right matrix"
+            )
+        );
+    };
+}
+
 impl<const M: usize, const K: usize> const Shape<2> for MatrixShape<M, K> {
     #[inline(always)]
     fn axis_len(&self, n: usize) -> usize {
@@ -109,6 +214,10 @@ impl<const M: usize, const K: usize> const Shape<2> for MatrixShape<M, K> {
         M * K
     }
     #[inline(always)]
+    fn strides(&self) -> [usize; 2] {
+        [K, 1]
+    }
+    #[inline(always)]
     fn slice(&self) -> &[usize; 2] {
         &[K, M]
     }
@@ -131,6 +240,98 @@ pub struct Tensor<
     pub shape: S,
 }
 
+impl<
+        T: Copy + std::ops::Add<Output = T>,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        S: Shape<NDIM>,
+        const NDIM: usize,
+        const LEN: usize,
+    > Tensor<T, U, B, NDIM, LEN, S>
+{
+    /// Add `other` to every slice of `self` with the same shape as `other`.
+    /// The shape of `other` must match the innermost axes of `self` (starting from axis 0),
+    /// fx. a vector with the width of a matrix can be added to every row of the matrix.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let mut a = [0., 1., 2., 3., 4., 5.].reshape([3, 2], slas_backend::Rust);
+    /// let b = [1., 2., 3.].reshape([3], slas_backend::Rust);
+    /// a.broadcast_add(&b);
+    ///
+    /// assert_eq!(a.data.data, [1., 3., 5., 4., 6., 8.]);
+    /// ```
+    pub fn broadcast_add<
+        U2: StaticVec<T, LEN2>,
+        S2: Shape<NDIM2>,
+        const NDIM2: usize,
+        const LEN2: usize,
+    >(
+        &mut self,
+        other: &Tensor<T, U2, B, NDIM2, LEN2, S2>,
+    ) {
+        assert!(
+            NDIM2 <= NDIM && (0..NDIM2).all(|n| other.shape.axis_len(n) == self.shape.axis_len(n)),
+            "Cannot broadcast tensor with shape [{}] to shape [{}]",
+            debug_shape(&other.shape),
+            debug_shape(&self.shape)
+        );
+        assert_eq!(self.shape.volume(), LEN);
+        assert_eq!(other.shape.volume(), LEN2);
+
+        let a = self.data.data.mut_moo_ref();
+        let b = other.data.data.moo_ref();
+        for outer in 0..LEN / LEN2 {
+            for n in 0..LEN2 {
+                a[outer * LEN2 + n] = a[outer * LEN2 + n] + b[n];
+            }
+        }
+    }
+}
+
+impl<
+        T,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        S: Shape<NDIM>,
+        const NDIM: usize,
+        const LEN: usize,
+    > Tensor<T, U, B, NDIM, LEN, S>
+{
+    /// Iterate over all elements of `self` together with their index,
+    /// like [`Iterator::enumerate`] on a flat vector.
+    /// The index is ordered by axis (starting from axis 0), so it can be used to index the tensor.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let t = moo![f32: 0..6].reshape([3, 2], slas_backend::Rust);
+    /// let mut iter = t.indexed_iter();
+    ///
+    /// assert_eq!(iter.next(), Some(([0, 0], &0.)));
+    /// assert_eq!(iter.next(), Some(([1, 0], &1.)));
+    /// assert_eq!(iter.last(), Some(([2, 1], &5.)));
+    ///
+    /// for (i, v) in t.indexed_iter() {
+    ///     assert_eq!(t[i], *v);
+    /// }
+    /// ```
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ([usize; NDIM], &T)> {
+        assert_eq!(self.shape.volume(), LEN);
+
+        let strides = self.shape.strides();
+        let lens: [usize; NDIM] = std::array::from_fn(|n| self.shape.axis_len(n));
+
+        (0..LEN).map(move |flat| {
+            let index = std::array::from_fn(|n| flat / strides[NDIM - 1 - n] % lens[n]);
+            (index, unsafe { self.data.data.get_unchecked(flat) })
+        })
+    }
+}
+
 impl<T, U: StaticVec<T, LEN>, B: Backend<T>, const LEN: usize, S: Shape<2>>
     Tensor<T, U, B, 2, LEN, S>
 {
@@ -192,6 +393,122 @@ impl<
     }
 }
 
+impl<
+        T,
+        B: Backend<T>,
+        S: Shape<2>,
+        U: StaticVec<T, LEN>,
+        const LEN: usize,
+        const IS_TRANS: bool,
+    > Matrix<T, U, B, LEN, IS_TRANS, S>
+{
+    /// Write self as a table, with one row per line.
+    /// Every cell is padded to the width of the widest cell (or the width of the formatter, if that is wider),
+    /// and aligned as specified by the formatter (right aligned by default).
+    fn fmt_table(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        cell: impl Fn(&T) -> String,
+    ) -> std::fmt::Result {
+        let m = self.rows();
+        let k = self.columns();
+        debug_assert_eq!(m * k, LEN);
+
+        let cells: Vec<String> = (0..m * k).map(|n| cell(&self[(n / k, n % k)])).collect();
+        let width = cells
+            .iter()
+            .map(|c| c.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(f.width().unwrap_or(0));
+
+        for r in 0..m {
+            if r != 0 {
+                f.write_str("\n")?;
+            }
+            for c in 0..k {
+                if c != 0 {
+                    f.write_str("  ")?;
+                }
+                let cell = &cells[r * k + c];
+                match f.align() {
+                    Some(std::fmt::Alignment::Left) => write!(f, "{cell:<width$}")?,
+                    Some(std::fmt::Alignment::Center) => write!(f, "{cell:^width$}")?,
+                    _ => write!(f, "{cell:>width$}")?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Formats the matrix as a table with aligned columns.
+/// The precision, width and alignment of the formatter are applied to every element.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let a = moo![f32: 1, -2.5, 10, 4].matrix::<slas_backend::Rust, 2, 2>();
+///
+/// assert_eq!(format!("{a}"), "   1  -2.5\n  10     4");
+/// assert_eq!(format!("{a:.2}"), " 1.00  -2.50\n10.00   4.00");
+/// assert_eq!(format!("{a:<.1}"), "1.0   -2.5\n10.0  4.0 ");
+/// ```
+impl<
+        T: std::fmt::Display,
+        B: Backend<T>,
+        S: Shape<2>,
+        U: StaticVec<T, LEN>,
+        const LEN: usize,
+        const IS_TRANS: bool,
+    > std::fmt::Display for Matrix<T, U, B, LEN, IS_TRANS, S>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match f.precision() {
+            Some(p) => self.fmt_table(f, |x| format!("{x:.p$}")),
+            None => self.fmt_table(f, |x| format!("{x}")),
+        }
+    }
+}
+
+macro_rules! impl_hex {
+    ($($trait: ident $fmt: literal $alt_fmt: literal),*) => {$(
+        /// Formats the matrix as a table of hex numbers with aligned columns, like the `Display` implementation.
+        /// The alternate flag (`{:#x}`) prefixes every element with `0x`.
+        ///
+        /// ## Example
+        /// ```rust
+        /// use slas::prelude::*;
+        ///
+        /// let a = [0x1i32, 0xab, 0xff, 0x10].matrix::<slas_backend::Rust, 2, 2>();
+        ///
+        /// assert_eq!(format!("{a:x}"), " 1  ab\nff  10");
+        /// assert_eq!(format!("{a:X}"), " 1  AB\nFF  10");
+        /// assert_eq!(format!("{a:<#x}"), "0x1   0xab\n0xff  0x10");
+        /// ```
+        impl<
+                T: std::fmt::$trait,
+                B: Backend<T>,
+                S: Shape<2>,
+                U: StaticVec<T, LEN>,
+                const LEN: usize,
+                const IS_TRANS: bool,
+            > std::fmt::$trait for Matrix<T, U, B, LEN, IS_TRANS, S>
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                if f.alternate() {
+                    self.fmt_table(f, |x| format!($alt_fmt, x))
+                } else {
+                    self.fmt_table(f, |x| format!($fmt, x))
+                }
+            }
+        }
+    )*};
+}
+
+impl_hex!(LowerHex "{:x}" "{:#x}", UpperHex "{:X}" "{:#X}");
+
 fn debug_shape<const NDIM: usize>(s: &dyn Shape<NDIM>) -> String {
     (0..NDIM)
         .map(|n| s.axis_len(n).to_string())
@@ -254,6 +571,69 @@ where
     }
 }
 
+/// Cast a runtime index to `[usize; NDIM]`, panicking if it has the wrong number of dimensions.
+#[inline(always)]
+fn dyn_index<const NDIM: usize>(i: &[usize]) -> &[usize; NDIM] {
+    i.try_into().unwrap_or_else(|_| {
+        panic!(
+            "Cannot index tensor with {NDIM} dimensions using index with {} dimensions",
+            i.len()
+        )
+    })
+}
+
+/// Index a tensor with a slice, for code that doesn't know the number of dimensions at compile time.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// let t = moo![f32: 0..6].reshape([3, 2], slas_backend::Rust);
+/// let i: Vec<usize> = vec![1, 1];
+/// assert_eq!(t[&i[..]], 4.);
+/// ```
+impl<
+        'i,
+        T,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        S: Shape<NDIM>,
+        const NDIM: usize,
+        const LEN: usize,
+    > std::ops::Index<&'i [usize]> for Tensor<T, U, B, NDIM, LEN, S>
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, i: &[usize]) -> &T {
+        unsafe {
+            self.data
+                .data
+                .get_unchecked(tensor_index(&self.shape, dyn_index(i)))
+        }
+    }
+}
+impl<
+        'i,
+        T,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        S: Shape<NDIM>,
+        const NDIM: usize,
+        const LEN: usize,
+    > std::ops::IndexMut<&'i [usize]> for Tensor<T, U, B, NDIM, LEN, S>
+where
+    T: Copy,
+{
+    fn index_mut(&mut self, i: &[usize]) -> &mut T {
+        unsafe {
+            self.data
+                .data
+                .get_unchecked_mut(tensor_index(&self.shape, dyn_index(i)))
+        }
+    }
+}
+
 impl<T, U: StaticVec<T, LEN>, B: Backend<T>, S: Shape<2>, const LEN: usize>
     std::ops::Index<(usize, usize)> for Tensor<T, U, B, 2, LEN, S>
 {
@@ -326,22 +706,46 @@ macro_rules! impl_index_slice {
             [(); NDIM - 1]: Sized,
             &'a $($mut)? U: StaticVec<T, LEN>,
         {
-            paste!{pub fn [<index_slice $(_$mut)?>] (&'a $($mut)? self, i: usize) -> Tensor<T, &'a $($mut)? [T; LEN], B, { NDIM - 1 }, LEN> {
+            paste!{
+            /// Returns the `i`th slice along the outermost axis (`NDIM - 1`), without copying.
+            /// `VOL` is the amount of elements in the slice, which is the product of the lengths of all the other axes.
+            /// It can usually not be inferred, so it has to be given explicitly.
+            /// See [`Tensor::index_slice_axis`] for slicing along other axes.
+            ///
+            /// ## Example
+            /// ```rust
+            /// use slas::prelude::*;
+            ///
+            /// let t = moo![f32: 0..24].reshape([4, 3, 2], slas_backend::Rust);
+            /// let s = t.index_slice::<12>(1);
+            ///
+            /// assert_eq!(s.shape, [4, 3]);
+            /// assert_eq!(s[[0, 0]], 12.);
+            /// ```
+            pub fn [<index_slice $(_$mut)?>]<const VOL: usize>(&'a $($mut)? self, i: usize) -> Tensor<T, &'a $($mut)? [T; VOL], B, { NDIM - 1 }, VOL> {
                 assert!(NDIM > 1);
-                assert!(i < self.shape.axis_len(0));
-
-                unsafe {
-                    transmute::<*const T, &'a $($mut)? [T; LEN]>(
-                        self.data
-                            .[< as $(_$mut)? _ptr>]()
-                            .add(i * (self.shape.volume() / self.shape.axis_len(NDIM - 1))),
-                    )
-                    .[<reshape_unchecked_ref $(_$mut)? >](
+                assert!(
+                    i < self.shape.axis_len(NDIM - 1),
+                    "Cannot index slice {i} of axis with length {}",
+                    self.shape.axis_len(NDIM - 1)
+                );
+                let volume = self.shape.volume() / self.shape.axis_len(NDIM - 1);
+                assert_eq!(
+                    volume, VOL,
+                    "Cannot index slice with {} elements as a tensor with {} elements",
+                    volume, VOL
+                );
+
+                Tensor {
+                    data: WithStaticBackend::from_static_vec(
+                        unsafe { self.data.data.[<$($mut _)? static_slice_unchecked>]::<VOL>(i * VOL) },
+                        B::default(),
+                    ),
+                    shape: unsafe {
                         *transmute::<*const usize, &[usize; NDIM - 1]>(
                             self.shape.slice()[0..NDIM - 1].as_ptr(),
-                        ),
-                        B::default(),
-                    )
+                        )
+                    },
                 }
             }}
         }
@@ -351,6 +755,93 @@ macro_rules! impl_index_slice {
 impl_index_slice!();
 impl_index_slice!(mut);
 
+impl<
+        T: Copy,
+        U: StaticVec<T, LEN>,
+        S: Shape<NDIM>,
+        B: Backend<T>,
+        const NDIM: usize,
+        const LEN: usize,
+    > Tensor<T, U, B, NDIM, LEN, S>
+where
+    [(); NDIM - 1]: Sized,
+{
+    /// Returns a copy of the `i`th slice along `axis`.
+    /// Unlike [`Tensor::index_slice`], the slice does not need to be contiguous in memory.
+    /// `VOL` is the amount of elements in the slice, which is the product of the lengths of all the other axes.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let t = moo![f32: 0..8].reshape([2, 2, 2], slas_backend::Rust);
+    /// let s = t.index_slice_axis::<4>(1, 0);
+    ///
+    /// assert_eq!(s.shape, [2, 2]);
+    /// assert_eq!(s[[0, 0]], 1.);
+    /// assert_eq!(s[[1, 0]], 3.);
+    /// assert_eq!(s[[0, 1]], 5.);
+    /// ```
+    pub fn index_slice_axis<const VOL: usize>(
+        &self,
+        i: usize,
+        axis: usize,
+    ) -> Tensor<T, [T; VOL], B, { NDIM - 1 }, VOL> {
+        assert!(NDIM > 1);
+        assert!(
+            axis < NDIM,
+            "Cannot slice axis {} of tensor with {} dimensions",
+            axis,
+            NDIM
+        );
+        assert!(
+            i < self.shape.axis_len(axis),
+            "Cannot index slice {i} of axis with length {}",
+            self.shape.axis_len(axis)
+        );
+
+        let mut shape = [0; NDIM - 1];
+        let mut strides = [0; NDIM - 1];
+        let mut offset = 0;
+        let mut stride = 1;
+        let mut k = 0;
+        for n in 0..NDIM {
+            if n == axis {
+                offset = i * stride;
+            } else {
+                shape[k] = self.shape.axis_len(n);
+                strides[k] = stride;
+                k += 1;
+            }
+            stride *= self.shape.axis_len(n);
+        }
+
+        assert_eq!(
+            shape.volume(),
+            VOL,
+            "Cannot index slice with {} elements as a tensor with {} elements",
+            shape.volume(),
+            VOL
+        );
+
+        let src = self.data.data.moo_ref();
+        let data = std::array::from_fn(|flat| {
+            let mut rem = flat;
+            let mut index = offset;
+            for n in 0..NDIM - 1 {
+                index += rem % shape[n] * strides[n];
+                rem /= shape[n];
+            }
+            src[index]
+        });
+
+        Tensor {
+            data: WithStaticBackend::from_static_vec(data, B::default()),
+            shape,
+        }
+    }
+}
+
 impl<
         T: Float + Sized,
         U: StaticVec<T, LEN>,
@@ -406,6 +897,32 @@ impl<
         );
     }
 
+    #[inline(always)]
+    pub fn matrix_mul<
+        U2: StaticVec<T, LEN2>,
+        const LEN2: usize,
+        const OLEN: usize,
+        const IS_TRANS_2: bool,
+        S2: Shape<2>,
+    >(
+        &self,
+        other: &Matrix<T, U2, B, LEN2, IS_TRANS_2, S2>,
+    ) -> [T; OLEN] {
+        let mut buffer = [T::_0; OLEN];
+        self.matrix_mul_buffer(other, &mut buffer);
+        buffer
+    }
+}
+
+impl<
+        T: Float + Sized,
+        U: StaticVec<T, LEN>,
+        B: Backend<T> + operations::MatrixVectorMul<T>,
+        const LEN: usize,
+        const IS_TRANS_1: bool,
+        S1: Shape<2>,
+    > Matrix<T, U, B, LEN, IS_TRANS_1, S1>
+{
     #[inline(always)]
     pub fn vector_mul_buffer<
         U2: StaticVec<T, LEN2>,
@@ -430,37 +947,633 @@ impl<
             &self.0.data.data,
             other,
             buffer,
-            self.0.shape.axis_len(0),
-            self.0.shape.axis_len(1),
+            self.rows(),
+            self.columns(),
             self.0.shape.axis_len(0),
             IS_TRANS_1,
         );
     }
 
+    #[inline(always)]
+    pub fn vector_mul<U2: StaticVec<T, LEN2>, const LEN2: usize, const OLEN: usize>(
+        &self,
+        other: &U2,
+    ) -> [T; OLEN] {
+        let mut buffer = [T::_0; OLEN];
+        self.vector_mul_buffer(other, &mut buffer);
+        buffer
+    }
+}
+
+impl<
+        T: Float,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    > Matrix<T, U, B, LEN, false, MatrixShape<M, K>>
+{
+    /// Stack `self` on top of `other`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    /// use slas::prelude::*;
+    ///
+    /// let a = [0f32, 1., 2., 3., 4., 5.].matrix::<slas_backend::Rust, 2, 3>();
+    /// let b = [6f32, 7., 8.].matrix::<slas_backend::Rust, 1, 3>();
+    ///
+    /// assert_eq!(*a.vstack(&b).vec_ref(), [0., 1., 2., 3., 4., 5., 6., 7., 8.]);
+    /// ```
+    pub fn vstack<U2: StaticVec<T, LEN2>, const LEN2: usize, const M2: usize>(
+        &self,
+        other: &Matrix<T, U2, B, LEN2, false, MatrixShape<M2, K>>,
+    ) -> Matrix<T, [T; (M + M2) * K], B, { (M + M2) * K }, false, MatrixShape<{ M + M2 }, K>>
+    where
+        [(); (M + M2) * K]:,
+        [(); M + M2]:,
+    {
+        assert_eq!(M * K, LEN);
+        assert_eq!(M2 * K, LEN2);
+
+        let mut data = [T::_0; (M + M2) * K];
+        unsafe {
+            std::ptr::copy_nonoverlapping(self.vec_ref().as_ptr(), data.as_mut_ptr(), LEN);
+            std::ptr::copy_nonoverlapping(
+                other.vec_ref().as_ptr(),
+                data.as_mut_ptr().add(LEN),
+                LEN2,
+            );
+        }
+        data.matrix::<B, { M + M2 }, K>()
+    }
+
+    /// Place `other` to the right of `self`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    /// use slas::prelude::*;
+    ///
+    /// let a = [0f32, 1., 2., 3.].matrix::<slas_backend::Rust, 2, 2>();
+    /// let b = [4f32, 5.].matrix::<slas_backend::Rust, 2, 1>();
+    ///
+    /// assert_eq!(*a.hstack(&b).vec_ref(), [0., 1., 4., 2., 3., 5.]);
+    /// ```
+    pub fn hstack<U2: StaticVec<T, LEN2>, const LEN2: usize, const K2: usize>(
+        &self,
+        other: &Matrix<T, U2, B, LEN2, false, MatrixShape<M, K2>>,
+    ) -> Matrix<T, [T; M * (K + K2)], B, { M * (K + K2) }, false, MatrixShape<M, { K + K2 }>>
+    where
+        [(); M * (K + K2)]:,
+        [(); K + K2]:,
+    {
+        assert_eq!(M * K, LEN);
+        assert_eq!(M * K2, LEN2);
+
+        let mut data = [T::_0; M * (K + K2)];
+        for r in 0..M {
+            unsafe {
+                let row = data.as_mut_ptr().add(r * (K + K2));
+                std::ptr::copy_nonoverlapping(self.vec_ref().as_ptr().add(r * K), row, K);
+                std::ptr::copy_nonoverlapping(other.vec_ref().as_ptr().add(r * K2), row.add(K), K2);
+            }
+        }
+        data.matrix::<B, M, { K + K2 }>()
+    }
+
+    /// Kronecker product of `self` and `other`.
+    /// The result has `M * M2` rows and `K * K2` columns,
+    /// and can be reshaped with `.matrix::<B, { M * M2 }, { K * K2 }>()`.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 1, 2].matrix::<slas_backend::Rust, 1, 2>();
+    /// let b = moo![f32: 1, 10].matrix::<slas_backend::Rust, 2, 1>();
+    ///
+    /// assert_eq!(a.kron(&b), [1., 2., 10., 20.]);
+    /// ```
+    pub fn kron<U2: StaticVec<T, LEN2>, const LEN2: usize, const M2: usize, const K2: usize>(
+        &self,
+        other: &Matrix<T, U2, B, LEN2, false, MatrixShape<M2, K2>>,
+    ) -> [T; M * M2 * K * K2]
+    where
+        [(); M * M2 * K * K2]:,
+    {
+        assert_eq!(M * K, LEN);
+        assert_eq!(M2 * K2, LEN2);
+
+        let columns = K * K2;
+        let mut data = [T::_0; M * M2 * K * K2];
+        for i in 0..M {
+            for j in 0..K {
+                let a = self[(i, j)];
+                for p in 0..M2 {
+                    for q in 0..K2 {
+                        data[(i * M2 + p) * columns + j * K2 + q] = a * other[(p, q)];
+                    }
+                }
+            }
+        }
+        data
+    }
+}
+
+impl<
+        T: Copy + PartialOrd,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const IS_TRANS: bool,
+        S: Shape<2>,
+    > Matrix<T, U, B, LEN, IS_TRANS, S>
+{
+    /// Element-wise maximum of `self` and `other`, using the rust backend.
+    /// The result can be made into a matrix again with [`StaticVec::matrix`].
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: -1, 2, -3, 4].matrix::<slas_backend::Rust, 2, 2>();
+    /// let b = moo![f32: 1, -2, 3, -4].matrix::<slas_backend::Rust, 2, 2>();
+    ///
+    /// assert_eq!(a.elementwise_max(&b), [1., 2., 3., 4.]);
+    /// assert_eq!(a.elementwise_min(&b), [-1., -2., -3., -4.]);
+    /// ```
+    pub fn elementwise_max(&self, other: &Self) -> [T; LEN]
+    where
+        Rust: operations::ElementwiseMax<T>,
+    {
+        let mut buffer = **self.0.data.data.moo_ref();
+        operations::ElementwiseMax::elementwise_max(
+            &Rust,
+            &self.0.data.data,
+            &other.0.data.data,
+            &mut buffer,
+        );
+        buffer
+    }
+
+    /// Element-wise minimum of `self` and `other`, using the rust backend.
+    /// See [`Matrix::elementwise_max`].
+    pub fn elementwise_min(&self, other: &Self) -> [T; LEN]
+    where
+        Rust: operations::ElementwiseMin<T>,
+    {
+        let mut buffer = **self.0.data.data.moo_ref();
+        operations::ElementwiseMin::elementwise_min(
+            &Rust,
+            &self.0.data.data,
+            &other.0.data.data,
+            &mut buffer,
+        );
+        buffer
+    }
+}
+
+impl<
+        T: Copy + Default,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    > Matrix<T, U, B, LEN, false, MatrixShape<M, K>>
+{
+    /// Copy of `self` where all elements below the main diagonal are zero.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 1..7].matrix::<slas_backend::Rust, 2, 3>();
+    /// assert_eq!(a.triu(), [1., 2., 3., 0., 5., 6.]);
+    /// ```
+    pub fn triu(&self) -> [T; LEN] {
+        self.triangle(|r, c| c >= r)
+    }
+
+    /// Copy of `self` where all elements above the main diagonal are zero.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 1..7].matrix::<slas_backend::Rust, 2, 3>();
+    /// assert_eq!(a.tril(), [1., 0., 0., 4., 5., 0.]);
+    /// ```
+    pub fn tril(&self) -> [T; LEN] {
+        self.triangle(|r, c| c <= r)
+    }
+
+    /// Copy of `self` with `TOP` rows of zeros above, `BOT` rows below,
+    /// `LEFT` columns of zeros to the left and `RIGHT` columns to the right.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 1, 2].matrix::<slas_backend::Rust, 1, 2>();
+    /// let p = a.pad::<1, 0, 0, 1>();
+    ///
+    /// assert_eq!(p.rows(), 2);
+    /// assert_eq!(p.columns(), 3);
+    /// assert_eq!(*p.vec_ref(), [0., 0., 0., 1., 2., 0.]);
+    /// ```
+    pub fn pad<const TOP: usize, const BOT: usize, const LEFT: usize, const RIGHT: usize>(
+        &self,
+    ) -> Matrix<
+        T,
+        [T; (M + TOP + BOT) * (K + LEFT + RIGHT)],
+        B,
+        { (M + TOP + BOT) * (K + LEFT + RIGHT) },
+        false,
+        MatrixShape<{ M + TOP + BOT }, { K + LEFT + RIGHT }>,
+    >
+    where
+        [(); (M + TOP + BOT) * (K + LEFT + RIGHT)]:,
+        [(); M + TOP + BOT]:,
+        [(); K + LEFT + RIGHT]:,
+    {
+        assert_eq!(M * K, LEN);
+
+        let columns = K + LEFT + RIGHT;
+        let mut data = [T::default(); (M + TOP + BOT) * (K + LEFT + RIGHT)];
+        for r in 0..M {
+            unsafe {
+                *data.mut_static_slice_unchecked::<K>((r + TOP) * columns + LEFT) =
+                    *self.vec_ref().static_slice_unchecked::<K>(r * K);
+            }
+        }
+        data.matrix::<B, { M + TOP + BOT }, { K + LEFT + RIGHT }>()
+    }
+
+    fn triangle(&self, keep: impl Fn(usize, usize) -> bool) -> [T; LEN] {
+        assert_eq!(M * K, LEN);
+
+        let mut data = [T::default(); LEN];
+        for r in 0..M {
+            for c in 0..K {
+                if keep(r, c) {
+                    data[r * K + c] = self[(r, c)];
+                }
+            }
+        }
+        data
+    }
+}
+
+impl<
+        T: Float,
+        U: StaticVec<Complex<T>, LEN>,
+        B: Backend<Complex<T>>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    > Matrix<Complex<T>, U, B, LEN, false, MatrixShape<M, K>>
+where
+    Rust: Backend<Complex<T>>,
+{
+    /// Hermitian (conjugate) transpose of a complex matrix.
+    /// Unlike [`Matrix::transpose`], the data is copied, as each element also needs to be conjugated.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let c = |re, im| Complex::<f32> { re, im };
+    /// let a = [c(1., 1.), c(2., -2.), c(3., 0.)].matrix::<slas_backend::Rust, 1, 3>();
+    /// let h = a.hermitian_transpose();
+    ///
+    /// assert_eq!(h.rows(), 3);
+    /// assert_eq!(h.columns(), 1);
+    /// assert_eq!(h[(1, 0)], c(2., 2.));
+    /// ```
+    pub fn hermitian_transpose(
+        self,
+    ) -> Matrix<Complex<T>, [Complex<T>; LEN], B, LEN, false, MatrixShape<K, M>> {
+        assert_eq!(M * K, LEN);
+
+        let mut buffer = **self.0.data.data.moo_ref();
+        Rust.transpose(&self.0.data.data, &mut buffer, M);
+        for n in buffer.iter_mut() {
+            n.im = T::_0 - n.im;
+        }
+        buffer.matrix::<B, K, M>()
+    }
+}
+
+impl<T: Copy, B: Backend<T>, const LEN: usize, S: Shape<2> + Default>
+    Matrix<T, [T; LEN], B, LEN, false, S>
+{
+    /// Matrix filled with `T::default()`, which is zero for all numeric types.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a: Matrix<f32, _, slas_backend::Rust, 6, false, MatrixShape<2, 3>> = Matrix::zeros();
+    /// assert_eq!(*a.vec_ref(), [0.; 6]);
+    /// ```
+    pub fn zeros() -> Self
+    where
+        T: Default,
+    {
+        Self::filled(T::default())
+    }
+
+    fn filled(value: T) -> Self {
+        let shape = S::default();
+        assert_eq!(
+            shape.volume(),
+            LEN,
+            "Cannot create matrix with shape [{}] from {} elements",
+            debug_shape(&shape),
+            LEN
+        );
+        Matrix(Tensor {
+            data: WithStaticBackend::from_static_vec([value; LEN], B::default()),
+            shape,
+        })
+    }
+}
+
+impl<T: Float, B: Backend<T>, const LEN: usize, S: Shape<2> + Default>
+    Matrix<T, [T; LEN], B, LEN, false, S>
+{
+    /// Matrix filled with ones.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a: Matrix<f32, _, slas_backend::Rust, 6, false, MatrixShape<2, 3>> = Matrix::ones();
+    /// assert_eq!(*a.vec_ref(), [1.; 6]);
+    /// ```
+    pub fn ones() -> Self {
+        Self::filled(T::_1)
+    }
+}
+
+impl<T: Copy + Default, B: Backend<T>, const LEN: usize, const N: usize>
+    Matrix<T, [T; LEN], B, LEN, false, MatrixShape<N, N>>
+{
+    /// Square matrix with `v` on the main diagonal, and zeros everywhere else.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a: Matrix<f32, _, slas_backend::Rust, 4, false, MatrixShape<2, 2>> =
+    ///     Matrix::from_diagonal(&[1., 2.]);
+    /// assert_eq!(*a.vec_ref(), [1., 0., 0., 2.]);
+    /// ```
+    pub fn from_diagonal(v: &impl StaticVec<T, N>) -> Self {
+        assert_eq!(
+            N * N,
+            LEN,
+            "Cannot create {N}x{N} diagonal matrix with {LEN} elements"
+        );
+
+        let mut data = [T::default(); LEN];
+        for i in 0..N {
+            data[i * N + i] = unsafe { *v.get_unchecked(i) };
+        }
+        data.matrix::<B, N, N>()
+    }
+
+    /// Square matrix with `d` on the main diagonal, and zeros everywhere else.
+    /// Fx. `lambda * I` for ridge regression.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a: Matrix<f32, _, slas_backend::Rust, 4, false, MatrixShape<2, 2>> =
+    ///     Matrix::from_scalar_diagonal(0.5);
+    /// assert_eq!(*a.vec_ref(), [0.5, 0., 0., 0.5]);
+    /// ```
+    pub fn from_scalar_diagonal(d: T) -> Self {
+        Self::from_diagonal(&[d; N])
+    }
+}
+
+impl<T: Float, B: Backend<T>, const LEN: usize, const N: usize>
+    Matrix<T, [T; LEN], B, LEN, false, MatrixShape<N, N>>
+{
+    /// Identity matrix, with ones on the main diagonal and zeros everywhere else.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let i: Matrix<f32, _, slas_backend::Rust, 9, false, MatrixShape<3, 3>> = Matrix::identity();
+    /// assert_eq!(*i.vec_ref(), [1., 0., 0., 0., 1., 0., 0., 0., 1.]);
+    /// assert!(i.is_identity(0.));
+    /// ```
+    pub fn identity() -> Self {
+        assert_eq!(
+            N * N,
+            LEN,
+            "Cannot create {N}x{N} identity matrix with {LEN} elements"
+        );
+
+        let mut data = [T::_0; LEN];
+        for i in 0..N {
+            data[i * N + i] = T::_1;
+        }
+        data.matrix::<B, N, N>()
+    }
+}
+
+impl<
+        T: Float + PartialOrd,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const IS_TRANS: bool,
+        const N: usize,
+    > Matrix<T, U, B, LEN, IS_TRANS, MatrixShape<N, N>>
+{
+    /// Returns true if all elements on the main diagonal are within `tol` of 1,
+    /// and all other elements are within `tol` of 0.
+    /// `tol = 0` checks for an exact identity matrix.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use slas::prelude::*;
+    ///
+    /// let a = moo![f32: 1.001, 0, -0.001, 1].matrix::<slas_backend::Rust, 2, 2>();
+    /// assert!(a.is_identity(0.01));
+    /// assert!(!a.is_identity(0.0001));
+    /// ```
+    pub fn is_identity(&self, tol: T) -> bool {
+        (0..N).all(|r| {
+            (0..N).all(|c| {
+                let err = self[(r, c)] - if r == c { T::_1 } else { T::_0 };
+                err <= tol && T::_0 - err <= tol
+            })
+        })
+    }
+}
+
+/// A `M`x`K` matrix stored in column-major (Fortran) order.
+///
+/// Element `(row, column)` is found at `column * M + row` in the underlying data.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::tensor::ColMajorMatrix;
+///
+/// let a: ColMajorMatrix<f32, _, slas_backend::Rust, 6, 2, 3> =
+///     ColMajorMatrix::from_col_major([1., 4., 2., 5., 3., 6.]);
+///
+/// assert_eq!(a[(0, 1)], 2.);
+/// assert_eq!(*a.to_row_major().vec_ref(), [1., 2., 3., 4., 5., 6.]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct ColMajorMatrix<
+    T,
+    U: StaticVec<T, LEN>,
+    B: Backend<T>,
+    const LEN: usize,
+    const M: usize,
+    const K: usize,
+>(Tensor<T, U, B, 2, LEN, MatrixShape<K, M>>);
+
+impl<
+        T: Copy,
+        U: StaticVec<T, LEN>,
+        B: Backend<T>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    > ColMajorMatrix<T, U, B, LEN, M, K>
+{
+    /// Interpret `data` as a `M`x`K` matrix in column-major order.
+    pub fn from_col_major(data: U) -> Self {
+        assert_eq!(
+            M * K,
+            LEN,
+            "Cannot reshape vector of {} elements as matrix of {}",
+            LEN,
+            M * K
+        );
+        ColMajorMatrix(Tensor {
+            data: WithStaticBackend::from_static_vec(data, B::default()),
+            shape: MatrixShape::<K, M>,
+        })
+    }
+
+    #[inline(always)]
+    pub fn rows(&self) -> usize {
+        M
+    }
+
+    #[inline(always)]
+    pub fn columns(&self) -> usize {
+        K
+    }
+
+    /// Copy the matrix into row-major order.
+    pub fn to_row_major(&self) -> Matrix<T, [T; LEN], B, LEN, false, MatrixShape<M, K>>
+    where
+        Rust: Backend<T>,
+    {
+        let mut buffer = **self.0.data.data.moo_ref();
+        Rust.transpose(&self.0.data.data, &mut buffer, K);
+        buffer.matrix::<B, M, K>()
+    }
+}
+
+impl<
+        T: Float,
+        U: StaticVec<T, LEN>,
+        B: Backend<T> + operations::MatrixMul<T>,
+        const LEN: usize,
+        const M: usize,
+        const K: usize,
+    > ColMajorMatrix<T, U, B, LEN, M, K>
+{
+    /// Multiply two column-major matricies, returning the product in column-major order.
+    ///
+    /// The operands are `A^T` and `B^T` in row-major order, so the product is computed as `C^T = B^T A^T`,
+    /// which means the backend is never asked to transpose either of them.
     #[inline(always)]
     pub fn matrix_mul<
         U2: StaticVec<T, LEN2>,
         const LEN2: usize,
+        const N: usize,
         const OLEN: usize,
-        const IS_TRANS_2: bool,
-        S2: Shape<2>,
     >(
         &self,
-        other: &Matrix<T, U2, B, LEN2, IS_TRANS_2, S2>,
+        other: &ColMajorMatrix<T, U2, B, LEN2, K, N>,
     ) -> [T; OLEN] {
+        assert_eq!(
+            M * N,
+            OLEN,
+            "ColMajorMatrix::matrix_mul expected output of {} elements, found one of {}",
+            M * N,
+            OLEN
+        );
+
         let mut buffer = [T::_0; OLEN];
-        self.matrix_mul_buffer(other, &mut buffer);
+        <B as Backend<T>>::matrix_mul(
+            &self.0.data.backend,
+            &other.0.data.data,
+            &self.0.data.data,
+            &mut buffer,
+            N,
+            M,
+            K,
+            K,
+            M,
+            M,
+            false,
+            false,
+        );
         buffer
     }
+}
+
+impl<T, U: StaticVec<T, LEN>, B: Backend<T>, const LEN: usize, const M: usize, const K: usize>
+    std::ops::Index<(usize, usize)> for ColMajorMatrix<T, U, B, LEN, M, K>
+{
+    type Output = T;
 
     #[inline(always)]
-    pub fn vector_mul<U2: StaticVec<T, LEN2>, const LEN2: usize, const OLEN: usize>(
-        &self,
-        other: &U2,
-    ) -> [T; OLEN] {
-        let mut buffer = [T::_0; OLEN];
-        self.vector_mul_buffer(other, &mut buffer);
-        buffer
+    fn index(&self, i: (usize, usize)) -> &T {
+        assert!(
+            i.0 < M && i.1 < K,
+            "Index {:?} out of bounds for {}x{} matrix",
+            i,
+            M,
+            K
+        );
+        unsafe { self.0.data.data.get_unchecked(i.1 * M + i.0) }
+    }
+}
+impl<T, U: StaticVec<T, LEN>, B: Backend<T>, const LEN: usize, const M: usize, const K: usize>
+    std::ops::IndexMut<(usize, usize)> for ColMajorMatrix<T, U, B, LEN, M, K>
+where
+    T: Copy,
+{
+    fn index_mut(&mut self, i: (usize, usize)) -> &mut T {
+        assert!(
+            i.0 < M && i.1 < K,
+            "Index {:?} out of bounds for {}x{} matrix",
+            i,
+            M,
+            K
+        );
+        unsafe { self.0.data.data.get_unchecked_mut(i.1 * M + i.0) }
     }
 }
 
@@ -480,7 +1593,7 @@ pub struct Matrix<
     const LEN: usize,
     const IS_TRANS: bool = false,
     S: Shape<2> = [usize; 2],
->(Tensor<T, U, B, 2, LEN, S>);
+>(pub(crate) Tensor<T, U, B, 2, LEN, S>);
 
 impl<
         T,
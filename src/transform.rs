@@ -0,0 +1,186 @@
+//! Geometric transformations.
+
+use crate::prelude::*;
+
+macro_rules! impl_rotation {
+    ($($t: ty),*) => {$(
+        impl<B: Backend<$t>> Matrix<$t, [$t; 4], B, 4, false, MatrixShape<2, 2>> {
+            /// Matrix rotating 2D vectors counter-clockwise by `angle` radians.
+            ///
+            /// ## Example
+            /// ```rust
+            /// use slas::prelude::*;
+            ///
+            /// let r = Matrix::<f32, _, slas_backend::Blas, 4, false, MatrixShape<2, 2>>::rotation_2d(std::f32::consts::FRAC_PI_2);
+            /// let v: [f32; 2] = r.vector_mul(&[1., 0.]);
+            /// assert!(v[0].abs() < 1e-6 && (v[1] - 1.).abs() < 1e-6);
+            /// ```
+            pub fn rotation_2d(angle: $t) -> Self {
+                let (s, c) = angle.sin_cos();
+                [c, -s, s, c].matrix::<B, 2, 2>()
+            }
+        }
+
+        impl<B: Backend<$t>> Matrix<$t, [$t; 9], B, 9, false, MatrixShape<3, 3>> {
+            /// Matrix rotating 3D vectors by `angle` radians around the x axis.
+            pub fn rotation_3d_x(angle: $t) -> Self {
+                let (s, c) = angle.sin_cos();
+                [1., 0., 0., 0., c, -s, 0., s, c].matrix::<B, 3, 3>()
+            }
+
+            /// Matrix rotating 3D vectors by `angle` radians around the y axis.
+            pub fn rotation_3d_y(angle: $t) -> Self {
+                let (s, c) = angle.sin_cos();
+                [c, 0., s, 0., 1., 0., -s, 0., c].matrix::<B, 3, 3>()
+            }
+
+            /// Matrix rotating 3D vectors by `angle` radians around the z axis.
+            pub fn rotation_3d_z(angle: $t) -> Self {
+                let (s, c) = angle.sin_cos();
+                [c, -s, 0., s, c, 0., 0., 0., 1.].matrix::<B, 3, 3>()
+            }
+
+            /// Matrix rotating 3D vectors by `angle` radians around `axis`, using the Rodrigues formula.
+            ///
+            /// ## Example
+            /// ```rust
+            /// use slas::prelude::*;
+            ///
+            /// let axis = UnitVec::try_from_vec(&[0., 0., 1.]).unwrap();
+            /// let r = Matrix::<f32, _, slas_backend::Rust, 9, false, MatrixShape<3, 3>>::rotation_3d_axis_angle(&axis, 1.);
+            /// let z = Matrix::<f32, _, slas_backend::Rust, 9, false, MatrixShape<3, 3>>::rotation_3d_z(1.);
+            ///
+            /// for n in 0..9 {
+            ///     assert!((r.vec_ref()[n] - z.vec_ref()[n]).abs() < 1e-6);
+            /// }
+            /// ```
+            pub fn rotation_3d_axis_angle(axis: &UnitVec<$t, 3>, angle: $t) -> Self {
+                let (s, c) = angle.sin_cos();
+                let t = 1. - c;
+                let (x, y, z) = (axis[0], axis[1], axis[2]);
+
+                [
+                    c + x.powi_(2) * t,
+                    x * y * t - z * s,
+                    x * z * t + y * s,
+                    y * x * t + z * s,
+                    c + y.powi_(2) * t,
+                    y * z * t - x * s,
+                    z * x * t - y * s,
+                    z * y * t + x * s,
+                    c + z.powi_(2) * t,
+                ]
+                .matrix::<B, 3, 3>()
+            }
+        }
+    )*};
+}
+
+impl_rotation!(f32, f64);
+
+/// An affine transformation of `N` dimensional points (translation, rotation, scaling, ...),
+/// stored as a `N+1`x`N+1` row-major matrix in homogeneous coordinates.
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+/// use slas::transform::Affine;
+///
+/// let a = Affine::translate(&[1., 2.]).compose(&Affine::scale(&[2., 3.]));
+/// assert_eq!(a.apply_point(&[1., 1.]), [3., 5.]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Affine<T: Float, const N: usize>([T; (N + 1) * (N + 1)])
+where
+    [(); (N + 1) * (N + 1)]:;
+
+impl<T: Float, const N: usize> Affine<T, N>
+where
+    [(); (N + 1) * (N + 1)]:,
+{
+    /// The transformation that leaves all points in place.
+    pub fn identity() -> Self {
+        let mut data = [T::_0; (N + 1) * (N + 1)];
+        for n in 0..=N {
+            data[n * (N + 1) + n] = T::_1;
+        }
+        Self(data)
+    }
+
+    /// Translation by `t`.
+    pub fn translate(t: &[T; N]) -> Self {
+        let mut a = Self::identity();
+        for (n, &t) in t.iter().enumerate() {
+            a.0[n * (N + 1) + N] = t;
+        }
+        a
+    }
+
+    /// Scaling by `s[n]` along axis `n`.
+    pub fn scale(s: &[T; N]) -> Self {
+        let mut a = Self::identity();
+        for (n, &s) in s.iter().enumerate() {
+            a.0[n * (N + 1) + n] = s;
+        }
+        a
+    }
+
+    /// Rotation (or reflection) by the orthogonal matrix `r`.
+    pub fn rotate<U: StaticVec<T, { N * N }>, B: Backend<T>>(
+        r: &crate::decompose::OrthogonalMatrix<T, U, B, N, N>,
+    ) -> Self
+    where
+        [(); N * N]:,
+    {
+        let mut a = Self::identity();
+        for row in 0..N {
+            for col in 0..N {
+                a.0[row * (N + 1) + col] = r[(row, col)];
+            }
+        }
+        a
+    }
+
+    /// Returns the transformation that applies `other` first, and then `self`.
+    pub fn compose(&self, other: &Self) -> Self {
+        let mut data = [T::_0; (N + 1) * (N + 1)];
+        for row in 0..=N {
+            for col in 0..=N {
+                let mut sum = T::_0;
+                for k in 0..=N {
+                    sum += self.0[row * (N + 1) + k] * other.0[k * (N + 1) + col];
+                }
+                data[row * (N + 1) + col] = sum;
+            }
+        }
+        Self(data)
+    }
+
+    /// Apply the transformation to the point `p`.
+    pub fn apply_point(&self, p: &[T; N]) -> [T; N] {
+        std::array::from_fn(|row| {
+            let mut sum = self.0[row * (N + 1) + N];
+            for (col, &p) in p.iter().enumerate() {
+                sum += self.0[row * (N + 1) + col] * p;
+            }
+            sum
+        })
+    }
+
+    /// Returns the homogeneous matrix of the transformation.
+    pub fn matrix<B: Backend<T>>(
+        &self,
+    ) -> Matrix<
+        T,
+        [T; (N + 1) * (N + 1)],
+        B,
+        { (N + 1) * (N + 1) },
+        false,
+        MatrixShape<{ N + 1 }, { N + 1 }>,
+    >
+    where
+        [(); N + 1]:,
+    {
+        self.0.matrix::<B, { N + 1 }, { N + 1 }>()
+    }
+}
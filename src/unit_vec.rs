@@ -0,0 +1,109 @@
+use crate::backends::{operations, Rust};
+use crate::prelude::*;
+
+/// Maximum distance from 1, the norm of a vector can have, to be considered a unit vector.
+pub const UNIT_VEC_EPSILON: f32 = 1e-5;
+
+/// A vector that is guaranteed to have a norm of 1.
+///
+/// Because the vector is always normalized,
+/// functions taking a `UnitVec` don't need to check or normalize their input at runtime.
+/// Mutable access to the underlying data is not allowed, as it could break the invariant.
+/// `UnitVec` implements [`StaticVec`], so it can be passed to functions that read from a `&impl StaticVec`,
+/// but getting a mutable pointer or reference from it panics, like for `&[T; LEN]`.
+/// Use [`UnitVec::to_vec`] to get an owned copy that can be mutated.
+///
+/// See also [`crate::tags::NormalizedStaticCowVec`], which is returned by [`StaticCowVec::normalize`].
+///
+/// ## Example
+/// ```rust
+/// use slas::prelude::*;
+///
+/// fn reflect(axis: &UnitVec<f32, 3>, v: &impl StaticVec<f32, 3>) -> [f32; 3] {
+///     let v = v.moo_ref();
+///     let d = 2. * v.dot(axis);
+///     let mut out = [0.; 3];
+///     for n in 0..3 {
+///         out[n] = v[n] - d * axis[n];
+///     }
+///     out
+/// }
+///
+/// let axis = UnitVec::try_from_vec(&[0., 2., 0.]).unwrap();
+/// assert_eq!(reflect(&axis, &[1., 2., 3.]), [1., -2., 3.]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct UnitVec<T: Float + 'static, const LEN: usize>(StaticVecUnion<'static, T, LEN>);
+
+impl<T: Float + PartialOrd + From<f32> + 'static, const LEN: usize> UnitVec<T, LEN>
+where
+    Rust: Backend<T> + operations::Normalize<T>,
+    T: From<<Rust as operations::Normalize<T>>::NormOutput>,
+{
+    /// Normalize a copy of `v` and return it as a unit vector.
+    /// Returns `None` if the normalized vector does not have a norm of 1 (fx. if `v` only contains zeros).
+    pub fn try_from_vec(v: &impl StaticVec<T, LEN>) -> Option<Self> {
+        let mut v = StaticVecUnion {
+            owned: **v.moo_ref(),
+        };
+        Rust.normalize(&mut v);
+
+        let err = <T as From<_>>::from(Rust.norm(&v)) - T::_1;
+        let eps = <T as From<f32>>::from(UNIT_VEC_EPSILON);
+        if err < eps && T::_0 - err < eps {
+            Some(Self(v))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Float + 'static, const LEN: usize> UnitVec<T, LEN> {
+    /// Create a unit vector without normalizing or checking the norm of `v`.
+    ///
+    /// # Safety
+    /// Is safe as long as the norm of `v` is 1.
+    pub unsafe fn from_vec_unchecked(v: &impl StaticVec<T, LEN>) -> Self {
+        Self(StaticVecUnion {
+            owned: **v.moo_ref(),
+        })
+    }
+
+    /// Copy the unit vector into an owned [`StaticCowVec`], which can be mutated freely.
+    pub fn to_vec(&self) -> StaticCowVec<'static, T, LEN> {
+        StaticCowVec::from(*self.0)
+    }
+}
+
+impl<T: Float + 'static, const LEN: usize> std::ops::Deref for UnitVec<T, LEN> {
+    type Target = StaticVecUnion<'static, T, LEN>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Float + 'static, const LEN: usize> AsRef<StaticVecUnion<'static, T, LEN>>
+    for UnitVec<T, LEN>
+{
+    fn as_ref(&self) -> &StaticVecUnion<'static, T, LEN> {
+        &self.0
+    }
+}
+
+impl<T: Float + 'static, const LEN: usize> StaticVec<T, LEN> for UnitVec<T, LEN> {
+    unsafe fn as_ptr(&self) -> *const T {
+        self.0.as_ptr()
+    }
+
+    /// Panics, as writing to a `UnitVec` could break the normalized invariant.
+    unsafe fn as_mut_ptr(&mut self) -> *mut T {
+        panic!("Cannot get mutable pointer from UnitVec. Maybe try UnitVec::to_vec instead.")
+    }
+}
+
+impl<T: Float + std::fmt::Debug + 'static, const LEN: usize> std::fmt::Debug for UnitVec<T, LEN> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
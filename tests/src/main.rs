@@ -7,6 +7,12 @@ use slas::prelude::*;
 #[macro_use]
 extern crate pretty_assertions;
 
+/// Element-wise comparison of float arrays, with some tolerance for rounding errors.
+#[cfg(test)]
+fn approx_eq<const LEN: usize>(a: &[f32; LEN], b: &[f32; LEN]) -> bool {
+    a.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-5)
+}
+
 //extern crate openblas_src;
 //extern crate blas_src;
 //use blas_src::*;
@@ -77,6 +83,149 @@ mod thin_blas {
         );
     }
 
+    #[test]
+    fn axpy() {
+        use slas::prelude::*;
+
+        let x = moo![f32: 0..5];
+        let mut blas = moo![on slas_backend::Blas:f32: 1..6];
+        let mut rust = moo![on slas_backend::Rust:f32: 1..6];
+        blas.axpy(-2., &x);
+        rust.axpy(-2., &x);
+
+        assert_eq!(***blas, [1., 0., -1., -2., -3.]);
+        assert_eq!(***blas, ***rust);
+    }
+
+    #[test]
+    fn scal() {
+        use slas::prelude::*;
+
+        let mut blas = moo![on slas_backend::Blas:f64: 0..5];
+        let mut rust = moo![on slas_backend::Rust:f64: 0..5];
+        blas.scal(0.5);
+        rust.scal(0.5);
+
+        assert_eq!(***blas, [0., 0.5, 1., 1.5, 2.]);
+        assert_eq!(***blas, ***rust);
+    }
+
+    #[test]
+    fn asum_iamax() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        let a = moo![f32: 1, -7, 3, 7, -2];
+        assert_eq!(Blas.asum(&a), 20.);
+        assert_eq!(Blas.iamax(&a), 1);
+        assert_eq!(Blas.asum(&a), Rust.asum(&a));
+        assert_eq!(Blas.iamax(&a), Rust.iamax(&a));
+    }
+
+    #[test]
+    fn static_backend_basic_ops() {
+        use slas::prelude::*;
+
+        let a = moo![on slas_backend::Rust:f32: 1..5];
+        let b = [2f32; 4].static_backend::<slas_backend::Rust>();
+
+        assert_eq!(a.add(&b), [3., 4., 5., 6.]);
+        assert_eq!(a.sub(&b), [-1., 0., 1., 2.]);
+        assert_eq!(a.mul(&b), [2., 4., 6., 8.]);
+        assert_eq!(a.div(&b), [0.5, 1., 1.5, 2.]);
+    }
+
+    #[test]
+    fn backend_wrapper() {
+        use slas_backend::{BackendLogger, BackendWrapper, Blas, Rust};
+        use std::sync::Mutex;
+
+        static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+        #[derive(Default)]
+        struct TestLogger;
+        impl BackendLogger for TestLogger {
+            fn log(&self, op: &str, _elapsed: std::time::Duration) {
+                LOG.lock().unwrap().push(op.to_string());
+            }
+        }
+
+        type Logged = BackendWrapper<Blas, TestLogger>;
+
+        let a = moo![on Logged: f32: 1, 2, 3, 4];
+        let b = [5f32, 6., 7., 8.].moo_ref().static_backend::<Logged>();
+        assert_eq!(
+            a.dot(&b),
+            moo![f32: 1, 2, 3, 4].dot(moo![f32: 5, 6, 7, 8].moo_ref())
+        );
+
+        let c: [f32; 4] = [1f32, 2., 3., 4.]
+            .matrix::<Logged, 2, 2>()
+            .matrix_mul(&[5f32, 6., 7., 8.].matrix::<Logged, 2, 2>());
+        assert_eq!(c, [19., 22., 43., 50.]);
+
+        assert!(Backend::<f32>::supports(
+            &Logged::default(),
+            slas_backend::BackendOp::MatrixMul
+        ));
+        assert_eq!(*LOG.lock().unwrap(), ["dot", "matrix_mul"]);
+
+        // The unit logger doesn't log anything.
+        let d = moo![on BackendWrapper<Rust, ()>: f32: 1, 2];
+        assert_eq!(d.dot(&d), 5.);
+    }
+
+    #[test]
+    fn auto_backend() {
+        let a = moo![auto f32: 1..=6];
+        let b = moo![auto f32: 6; 6];
+        assert_eq!(a.dot(&b), 126.);
+
+        let c: slas::backends::WithStaticBackend<f32, _, slas_backend::DefaultBackend, 4> =
+            moo![auto f32: 0..4];
+        assert!((c.norm() - 14f32.sqrt()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn weighted_dot() {
+        use slas::prelude::*;
+
+        let a = moo![f64: 0..11];
+        let b = moo![f64: 1..12];
+        let w = [0.5f64; 11];
+        let expected: f64 = (0..11).map(|n| 0.5 * (n * (n + 1)) as f64).sum();
+
+        assert_eq!(slas::backends::weighted_dot(&a, &b, &w), expected);
+
+        let a = moo![on slas_backend::Blas:f64: 0..11];
+        let b = moo![on slas_backend::Blas:f64: 1..12];
+        let w = w.static_backend::<slas_backend::Blas>();
+        assert_eq!(a.weighted_dot(&b, &w), expected);
+    }
+
+    #[test]
+    fn supports() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        assert!(Backend::<f64>::supports(&Blas, BackendOp::DotProduct));
+        assert!(Backend::<f64>::supports(
+            &Blas,
+            BackendOp::SymmetricMatrixVectorMul
+        ));
+        assert!(!Backend::<f64>::supports(&Blas, BackendOp::Addition));
+        assert!(Backend::<f64>::supports(&Rust, BackendOp::Addition));
+        assert!(!Backend::<Complex<f32>>::supports(
+            &Rust,
+            BackendOp::DotProduct
+        ));
+        assert!(Backend::<i32>::supports(
+            &Rust,
+            BackendOp::SaturatingAddition
+        ));
+        assert!(Backend::<Dual<f32>>::supports(&Rust, BackendOp::DotProduct));
+    }
+
     #[test]
     fn static_backend_macro() {
         use slas::prelude::*;
@@ -105,6 +254,47 @@ mod numbers {
         assert_eq!(a * b, Complex { re: 1., im: 21. });
     }
 
+    #[test]
+    fn complex_polar() {
+        use slas::prelude::*;
+
+        let c = Complex::<f32> { re: 3., im: -4. };
+        assert_eq!(c.modulus(), 5.);
+        assert_eq!(c.phase(), (-4f32).atan2(3.));
+
+        let (r, theta) = c.to_polar();
+        let d = Complex::<f32>::from_polar(r, theta);
+        assert!((d.re - c.re).abs() < 1e-5);
+        assert!((d.im - c.im).abs() < 1e-5);
+
+        assert_eq!(
+            Complex::<f64> { re: -1., im: 0. }.phase(),
+            std::f64::consts::PI
+        );
+    }
+
+    #[test]
+    fn complex_exp_ln() {
+        use slas::prelude::*;
+        use std::f64::consts::PI;
+
+        let e = Complex::<f64>::cis(PI);
+        assert!((e.re + 1.).abs() < f64::EPSILON);
+        assert!(e.im.abs() < f64::EPSILON * 2.);
+
+        let i = Complex::<f64>::cis(PI / 2.);
+        assert!(i.re.abs() < f64::EPSILON && (i.im - 1.).abs() < f64::EPSILON);
+
+        let z = Complex::<f64> { re: 0.5, im: -1.25 };
+        let w = z.cexp();
+        assert!((w.modulus() - 0.5f64.exp()).abs() < 1e-12);
+        assert!((w.phase() + 1.25).abs() < 1e-12);
+
+        let back = w.cln();
+        assert!((back.re - z.re).abs() < 1e-12);
+        assert!((back.im - z.im).abs() < 1e-12);
+    }
+
     #[test]
     fn complex_pow() {
         use slas::prelude::*;
@@ -130,6 +320,135 @@ mod numbers {
 mod moo {
     use crate::*;
 
+    #[test]
+    fn dynamic_moo_ref_infers_len() {
+        fn first_of_3(v: StaticVecRef<f32, 3>) -> f32 {
+            v[0]
+        }
+        fn zero_4(v: MutStaticVecRef<f32, 4>) {
+            **v = [0.; 4];
+        }
+
+        let a = vec![1f32, 2., 3.];
+        assert_eq!(first_of_3(a.moo_ref()), 1.);
+        assert_eq!(a.moo_ref().dot(moo![f32: 1, 1, 1].moo_ref()), 6.);
+
+        let mut b = vec![1f32; 4];
+        zero_4(b.mut_moo_ref());
+        assert_eq!(b, [0.; 4]);
+
+        let c: StaticVecRef<f32, 3> = a[..].moo_ref();
+        assert_eq!(c[2], 3.);
+    }
+
+    #[test]
+    fn split_at() {
+        let v = moo![f32: 0..6];
+        let (a, b) = v.split_at::<4>();
+        assert_eq!(a, &[0., 1., 2., 3.]);
+        assert_eq!(b, &[4., 5.]);
+
+        let (a, b) = v.split_at::<6>();
+        assert_eq!(a, &[0., 1., 2., 3., 4., 5.]);
+        assert_eq!(b, &[]);
+
+        // Writing to a borrowed cow copies it, and leaves the original untouched.
+        let original = [1f32, 2., 3.];
+        let mut cow = original.moo();
+        let (a, b) = cow.split_at_mut::<1>();
+        a[0] = 0.;
+        b[1] = 0.;
+        assert_eq!(**cow, [0., 2., 0.]);
+        assert_eq!(original, [1., 2., 3.]);
+    }
+
+    #[test]
+    fn rotate() {
+        let v = moo![f32: 0..7];
+        assert_eq!(*v.rotate_left::<3>(), [3., 4., 5., 6., 0., 1., 2.]);
+        assert_eq!(*v.rotate_right::<3>(), [4., 5., 6., 0., 1., 2., 3.]);
+        assert_eq!(*v.rotate_left::<3>().rotate_right::<3>(), **v);
+
+        assert_eq!(*v.rotate_left::<0>(), **v);
+        assert_eq!(*v.rotate_right::<7>(), **v);
+    }
+
+    #[test]
+    fn iter_mut_cow() {
+        let data = [1f32, 2., 3.];
+        let mut v = data.moo();
+        assert!(v.is_borrowed());
+
+        for (n, x) in v.iter_mut_cow().enumerate() {
+            *x += n as f32;
+        }
+        assert!(v.is_owned());
+        assert_eq!(**v, [1., 3., 5.]);
+        assert_eq!(data, [1., 2., 3.]);
+    }
+
+    #[test]
+    fn clone_then_mutate_copies_once() {
+        // The elements of a StaticCowVec are Copy, so they cannot implement Drop to trace copies.
+        // Instead a copy is detected by the data moving away from where it was before.
+        let data = [1f32, 2., 3.];
+        let a = data.moo();
+
+        #[allow(clippy::clone_on_copy)]
+        let mut b = a.clone();
+        assert!(b.is_borrowed());
+        assert_eq!(unsafe { b.as_ptr() }, unsafe { data.as_ptr() });
+
+        b[0] = 0.;
+        assert!(b.is_owned());
+        let copy = unsafe { b.as_ptr() };
+        assert_ne!(copy, unsafe { data.as_ptr() });
+
+        b[1] = 0.;
+        assert_eq!(unsafe { b.as_ptr() }, copy);
+        assert_eq!(**b, [0., 0., 3.]);
+        assert_eq!(data, [1., 2., 3.]);
+
+        #[allow(clippy::clone_on_copy)]
+        let mut c = a.clone().into_owned();
+        assert!(c.is_owned());
+        let owned = unsafe { c.as_ptr() };
+
+        c[0] = 5.;
+        assert_eq!(unsafe { c.as_ptr() }, owned);
+        assert_eq!(**a, data);
+    }
+
+    #[test]
+    fn windows_ref() {
+        let data = [1f32, 2., 3., 4., 5.];
+        let v = data.moo();
+
+        let windows: Vec<_> = v.windows_ref::<3>().collect();
+        assert_eq!(windows.len(), 3);
+        for (n, w) in windows.iter().enumerate() {
+            assert_eq!(***w, [data[n], data[n + 1], data[n + 2]]);
+            // Borrowed cows are not copied.
+            assert_eq!(unsafe { w.as_ptr() }, data[n..].as_ptr());
+        }
+
+        assert_eq!(v.windows_ref::<5>().count(), 1);
+    }
+
+    #[test]
+    fn chunks() {
+        let v = moo![f32: 0..12];
+        let chunks: Vec<&[f32; 3]> = v.chunks::<3>().collect();
+
+        assert_eq!(chunks.len(), 4);
+        for (n, chunk) in chunks.iter().enumerate() {
+            let start = (n * 3) as f32;
+            assert_eq!(**chunk, [start, start + 1., start + 2.]);
+        }
+        // The chunks borrow from the vector.
+        assert_eq!(unsafe { chunks[0].as_ptr() }, unsafe { v.as_ptr() });
+    }
+
     #[test]
     fn norm_complex_2d() {
         let c = Complex::<f32> { re: 1.2, im: 2.3 };
@@ -142,6 +461,109 @@ mod moo {
         assert_eq!(a.norm(), 3.668_787_2);
     }
 
+    #[test]
+    fn fill_and_closure() {
+        const FILL: f64 = 1.5;
+        assert_eq!(**moo![f64: FILL; 4], [1.5; 4]);
+        assert_eq!(**moo![f32: 3; 2], [3., 3.]);
+        assert!(moo![f32: f32::INFINITY; 5].iter().all(|n| n.is_infinite()));
+
+        let offsets = vec![10., 20., 30.];
+        let a = moo![move |i: usize| -> f64 { offsets[i] + i as f64 }; 3];
+        assert_eq!(**a, [10., 21., 32.]);
+
+        let b = moo![|i| i as i32 * 2; 4];
+        assert_eq!(**b, [0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn from_iterator() {
+        const N: usize = 4;
+        let lines = "1.5\n2\n-3\n4\n5";
+        let a = moo![f64, N: lines.lines().map(|l| l.parse().unwrap())];
+        assert_eq!(**a, [1.5, 2., -3., 4.]);
+
+        assert_eq!(**moo![f32, 2: vec![7., 8.]], [7., 8.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_short_iterator() {
+        moo![f32, 4: [1., 2., 3.]];
+    }
+
+    #[test]
+    fn from_fn() {
+        let v = StaticCowVec::<f64, 5>::from_fn(|n| (n * n) as f64);
+        assert!(v.is_owned());
+        assert_eq!(**v, [0., 1., 4., 9., 16.]);
+
+        let mut calls = 0;
+        let c = StaticCowVec::<Complex<f32>, 2>::from_fn(|n| {
+            calls += 1;
+            Complex {
+                re: n as f32,
+                im: 1.,
+            }
+        });
+        assert_eq!(calls, 2);
+        assert_eq!(c[1], Complex { re: 1., im: 1. });
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let data = [0f64, 1., 2., 3., 4.];
+
+        let v = StaticCowVec::<f64, 5>::try_from_slice(&data[..]).unwrap();
+        assert!(v.is_borrowed());
+        assert_eq!(**v, data);
+
+        let err = StaticCowVec::<f64, 3>::try_from_slice(&data[1..]).unwrap_err();
+        assert_eq!(err.expected, 3);
+        assert_eq!(err.found, 4);
+        assert_eq!(
+            err.to_string(),
+            "Cannot convert slice of length 4 to static vector of length 3"
+        );
+    }
+
+    #[test]
+    fn cumsum() {
+        assert_eq!(*[1., 2., 3., 4.].cumsum(), [1., 3., 6., 10.]);
+        assert_eq!(*moo![f32: 0..6].cumsum(), [0., 1., 3., 6., 10., 15.]);
+        assert_eq!(*[7i32].cumsum(), [7]);
+    }
+
+    #[test]
+    fn clamp_elements() {
+        let a = moo![f32: -3, -0.5, 0, 0.25, 1, 7];
+        assert_eq!(*a.clamp_elements(-1., 0.5), [-1., -0.5, 0., 0.25, 0.5, 0.5]);
+
+        let mut out = [0f32; 6];
+        a.clamp_elements_into(0., 1., out.mut_moo_ref());
+        assert_eq!(out, [0., 0., 0., 0.25, 1., 1.]);
+    }
+
+    #[test]
+    fn clamp_elements_int() {
+        assert_eq!(*[1i32, 5, -2].clamp_elements(0, 3), [1, 3, 0]);
+
+        let mut out = [0i64; 3];
+        [1i64, 5, -2].clamp_elements_into(0, 3, out.mut_moo_ref());
+        assert_eq!(out, [1, 3, 0]);
+    }
+
+    #[test]
+    fn argmax_argmin() {
+        assert_eq!([1., 2., 9., 3., 9.].argmax(), 2);
+        assert_eq!([-4., -3., -7., -1., -2., -5.].argmax(), 3);
+        assert_eq!(moo![f32: 0..13].argmax(), 12);
+
+        assert_eq!([1., 2., -9., 3., -9.].argmin(), 2);
+        assert_eq!([4., 3., 7., 1., 2., 5.].argmin(), 3);
+        assert_eq!([0i32; 6].argmin(), 0);
+    }
+
     #[test]
     fn vec_ref_dot() {
         assert_eq!([1f32, 2., 3.,].moo_ref().dot([1., 2., 3.,].moo_ref()), 14.)
@@ -193,6 +615,7 @@ mod moo {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn dot_complex() {
         let c = Complex::<f32> { re: 1., im: 2. };
         let a = moo![c; 5];
@@ -200,6 +623,27 @@ mod moo {
         assert_eq!(a.dot(&b), Complex { re: -15., im: 20. })
     }
 
+    #[test]
+    fn inner_product_complex() {
+        let a = moo![|n| -> Complex<f64> { Complex { re: n as f64, im: 1. - n as f64 } }; 5];
+        let b = moo![|n| -> Complex<f64> { Complex { re: 2., im: n as f64 * 0.5 } }; 5];
+
+        let mut expected = Complex { re: 0., im: 0. };
+        for n in 0..5 {
+            expected.re += a[n].re * b[n].re + a[n].im * b[n].im;
+            expected.im += a[n].re * b[n].im - a[n].im * b[n].re;
+        }
+
+        assert_eq!(a.inner_product(&b), expected);
+        assert_eq!(slas_backend::Rust.inner_product(&a, &b), expected);
+
+        // The inner product of a vector with itself is its squared norm.
+        let norm = a.inner_product(&a);
+        assert_eq!(norm.im, 0.);
+        let squared_norm: f64 = a.iter().map(|c| c.re * c.re + c.im * c.im).sum();
+        assert!((norm.re - squared_norm).abs() < 1e-9);
+    }
+
     #[test]
     fn unsafe_mutations() {
         let mut a: Vec<f32> = vec![1., 2., 3.2];
@@ -210,6 +654,68 @@ mod moo {
         assert_eq!(**b, [2., 2., 3.2]);
     }
 
+    #[test]
+    fn from_ref() {
+        let a = [1f32, 2., 3.];
+
+        let mut b = StaticCowVec::from_ref(&a);
+        assert!(b.is_borrowed());
+        assert_eq!(unsafe { b.as_ptr() }, unsafe { a.as_ptr() });
+
+        b[0] = 0.;
+        assert!(b.is_owned());
+        assert_eq!(**b, [0., 2., 3.]);
+        assert_eq!(a, [1., 2., 3.]);
+    }
+
+    #[test]
+    fn as_slice() {
+        let data = [1f32, 2., 3.];
+        let mut v = data.moo();
+
+        assert_eq!(v.as_slice(), &data);
+        assert_eq!(v.as_slice().as_ptr(), unsafe { data.as_ptr() });
+        assert!(v.is_borrowed());
+
+        v.as_slice_mut()[0] = 0.;
+        assert!(v.is_owned());
+        assert_ne!(v.as_slice().as_ptr(), unsafe { data.as_ptr() });
+        assert_eq!(v.as_slice(), &[0., 2., 3.]);
+        assert_eq!(data, [1., 2., 3.]);
+    }
+
+    #[test]
+    fn scatter_gather() {
+        let x = moo![f32: 0..8];
+        let indices = [7, 2, 2, 5];
+
+        let g = gather(&x, &indices);
+        assert_eq!(g, [7., 2., 2., 5.]);
+
+        // Sparse update of a dense vector.
+        let mut y = [0f32; 8];
+        scatter(&[1., 2., 3.], &[6, 0, 6], &mut y);
+        assert_eq!(y, [2., 0., 0., 0., 0., 0., 3., 0.]);
+
+        // Scattering gathered values back in place is the identity.
+        let mut z = x;
+        scatter(&g, &indices, &mut z);
+        assert_eq!(**z, **x);
+        assert!(z.is_owned());
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_out_of_bounds() {
+        gather(&[1f32, 2., 3.], &[0, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scatter_out_of_bounds() {
+        scatter(&[1f32], &[4], &mut [0f32; 4]);
+    }
+
     #[test]
     fn from_readme() {
         let mut source: Vec<f32> = vec![1., 2., 3.];
@@ -253,6 +759,203 @@ mod moo {
 mod tensors {
     use std::ops::DerefMut;
 
+    #[test]
+    fn display() {
+        use slas::prelude::*;
+
+        let a = [1i32, -20, 300, 4, 5, 6].matrix::<slas_backend::Rust, 2, 3>();
+        assert_eq!(format!("{a}"), "  1  -20  300\n  4    5    6");
+        assert_eq!(format!("{a:5}"), "    1    -20    300\n    4      5      6");
+        assert_eq!(format!("{a:^}"), " 1   -20  300\n 4    5    6 ");
+
+        let b = moo![f64: 0.125, 1, 2, 3.5].matrix::<slas_backend::Rust, 2, 2>();
+        assert_eq!(format!("{b:.3}"), "0.125  1.000\n2.000  3.500");
+    }
+
+    #[test]
+    fn hex() {
+        use slas::prelude::*;
+
+        let a = [0x63i64, 0x7c, 0x77, 0x7b, 0xf2, 0x6b, 0x6f, 0xc5, 0x30]
+            .matrix::<slas_backend::Rust, 3, 3>();
+        assert_eq!(format!("{a:x}"), "63  7c  77\n7b  f2  6b\n6f  c5  30");
+        assert_eq!(format!("{a:X}"), "63  7C  77\n7B  F2  6B\n6F  C5  30");
+        assert_eq!(
+            format!("{a:#x}"),
+            "0x63  0x7c  0x77\n0x7b  0xf2  0x6b\n0x6f  0xc5  0x30"
+        );
+
+        let b = [1i32, 0x100].matrix::<slas_backend::Rust, 1, 2>();
+        assert_eq!(format!("{b:x}"), "  1  100");
+    }
+
+    #[test]
+    fn from_diagonal() {
+        use slas::prelude::*;
+
+        let a: Matrix<f64, _, slas_backend::Rust, 9, false, MatrixShape<3, 3>> =
+            Matrix::from_diagonal(&moo![f64: -1, 2, 3.5]);
+        for r in 0..3 {
+            for c in 0..3 {
+                let expected = if r == c { [-1., 2., 3.5][r] } else { 0. };
+                assert_eq!(a[(r, c)], expected);
+            }
+        }
+
+        // A scaled identity matrix scales vectors.
+        let b: Matrix<f64, _, slas_backend::Rust, 9, false, MatrixShape<3, 3>> =
+            Matrix::from_scalar_diagonal(2.);
+        let v = [1., -2., 3.].matrix::<slas_backend::Rust, 3, 1>();
+        assert_eq!(b.matrix_mul(&v), [2., -4., 6.]);
+    }
+
+    #[test]
+    fn zeros_ones() {
+        use slas::prelude::*;
+
+        let mut grad: Matrix<f32, _, slas_backend::Rust, 6, false, MatrixShape<2, 3>> =
+            Matrix::zeros();
+        let ones: Matrix<f32, _, slas_backend::Rust, 6, false, MatrixShape<2, 3>> = Matrix::ones();
+        assert_eq!(*grad.vec_ref(), [0.; 6]);
+        assert_eq!(*ones.vec_ref(), [1.; 6]);
+
+        for _ in 0..3 {
+            for r in 0..2 {
+                for c in 0..3 {
+                    grad[(r, c)] += ones[(r, c)];
+                }
+            }
+        }
+        assert_eq!(grad[(1, 2)], 3.);
+    }
+
+    #[test]
+    fn identity() {
+        use slas::prelude::*;
+
+        let i: Matrix<f64, _, slas_backend::Rust, 16, false, MatrixShape<4, 4>> =
+            Matrix::identity();
+        assert!(i.is_identity(0.));
+
+        let a = moo![f64: 0..16].matrix::<slas_backend::Rust, 4, 4>();
+        assert_eq!(a.matrix_mul(&i), ***a.vec_ref());
+        assert!(!a.is_identity(0.5));
+
+        // A rotation matrix times its transpose is the identity, up to rounding errors.
+        let (s, c) = 0.3f64.sin_cos();
+        let r = [c, -s, s, c].matrix::<slas_backend::Rust, 2, 2>();
+        let rrt: [f64; 4] = r.matrix_mul(r.as_transposed());
+        assert!(rrt.matrix::<slas_backend::Rust, 2, 2>().is_identity(1e-12));
+    }
+
+    #[test]
+    fn hermitian_transpose() {
+        use slas::prelude::*;
+
+        let data: [Complex<f64>; 6] = std::array::from_fn(|n| Complex {
+            re: n as f64,
+            im: 1. - n as f64 * 0.5,
+        });
+        let a = data.matrix::<slas_backend::Rust, 2, 3>();
+        let h = a.hermitian_transpose();
+
+        assert_eq!(h.rows(), 3);
+        assert_eq!(h.columns(), 2);
+        for r in 0..2 {
+            for c in 0..3 {
+                let (x, y) = (data[r * 3 + c], h[(c, r)]);
+                assert_eq!(y.re, x.re);
+                assert_eq!(y.im, -x.im);
+            }
+        }
+    }
+
+    #[test]
+    fn elementwise_max_min() {
+        use slas::prelude::*;
+
+        let a = moo![f64: -5, 3.5, -0.5, 7, -2, 0, 9, -8, 1].matrix::<slas_backend::Rust, 3, 3>();
+        let b = moo![f64: -6, 4, -1, -7, 2, -0.25, 8, -9, 1].matrix::<slas_backend::Rust, 3, 3>();
+
+        let max = a.elementwise_max(&b);
+        let min = a.elementwise_min(&b);
+        assert_eq!(max, [-5., 4., -0.5, 7., 2., 0., 9., -8., 1.]);
+        assert_eq!(min, [-6., 3.5, -1., -7., -2., -0.25, 8., -9., 1.]);
+
+        let max = max.matrix::<slas_backend::Rust, 3, 3>();
+        for r in 0..3 {
+            for c in 0..3 {
+                assert!(max[(r, c)] >= a[(r, c)] && max[(r, c)] >= b[(r, c)]);
+            }
+        }
+    }
+
+    #[test]
+    fn slice_index() {
+        use slas::prelude::*;
+
+        fn sum_diagonal<T: std::ops::Index<&'static [usize], Output = f32>>(
+            t: &T,
+            indices: &'static [&'static [usize]],
+        ) -> f32 {
+            indices.iter().map(|i| t[*i]).sum()
+        }
+
+        let mut t = moo![f32: 0..24].reshape([4, 3, 2], slas_backend::Rust);
+        for (i, n) in t.indexed_iter() {
+            assert_eq!(t[&i[..]], *n);
+        }
+
+        t[&[1, 1, 1][..]] = 100.;
+        assert_eq!(t[[1, 1, 1]], 100.);
+        assert_eq!(sum_diagonal(&t, &[&[0, 0, 0], &[1, 1, 1]]), 100.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_index_wrong_ndim() {
+        use slas::prelude::*;
+
+        let t = moo![f32: 0..6].reshape([3, 2], slas_backend::Rust);
+        let _n: f32 = t[&[0, 0, 0][..]];
+    }
+
+    #[test]
+    fn indexed_iter() {
+        use slas::prelude::*;
+
+        let t = moo![f32: 0..24].reshape([2, 3, 4], slas_backend::Rust);
+        let mut count = 0;
+        for (n, (i, v)) in t.indexed_iter().enumerate() {
+            assert_eq!(i, [n % 2, n / 2 % 3, n / 6]);
+            assert_eq!(t[i], *v);
+            count += 1;
+        }
+        assert_eq!(count, 24);
+
+        let m = moo![f32: 0..6].matrix::<slas_backend::Rust, 2, 3>();
+        let indices: Vec<[usize; 2]> = m.indexed_iter().map(|(i, _)| i).collect();
+        assert_eq!(indices[4], [1, 1]);
+    }
+
+    #[test]
+    fn pad_asymmetric() {
+        use slas::prelude::*;
+
+        let a = moo![f32: 1..7].matrix::<slas_backend::Rust, 2, 3>();
+        let p = a.pad::<1, 2, 3, 0>();
+
+        assert_eq!(p.rows(), 5);
+        assert_eq!(p.columns(), 6);
+        assert_eq!(
+            *p.vec_ref(),
+            [
+                0., 0., 0., 0., 0., 0., 0., 0., 0., 1., 2., 3., 0., 0., 0., 4., 5., 6., 0., 0., 0.,
+                0., 0., 0., 0., 0., 0., 0., 0., 0.,
+            ]
+        );
+    }
+
     #[test]
     fn make_matrix_ref() {
         use slas::prelude::*;
@@ -330,15 +1033,35 @@ mod tensors {
     }
 
     #[test]
-    fn matrix_mul_trans_a2() {
+    fn rust_matrix_mul() {
         use slas::prelude::*;
         use slas_backend::*;
-        let a = moo![f32: 1..=6].matrix::<Blas, 2, 3>();
-        let b = moo![f32: 1..=6].matrix::<Blas, 2, 3>();
-
-        let c = a.matrix_mul(&b.transpose());
-        assert_eq!(c, [14., 32., 32., 77.]);
-    }
+
+        let a = moo![f32: 1..=6].matrix::<Rust, 2, 3>();
+        let b = moo![f32: 1..=6].matrix::<Rust, 2, 3>();
+        assert_eq!(
+            a.transpose().matrix_mul(&b),
+            [17., 22., 27., 22., 29., 36., 27., 36., 45.]
+        );
+        assert_eq!(a.matrix_mul(&b.transpose()), [14., 32., 32., 77.]);
+
+        // Large enough for the blas backend to call blas, with the default `SLAS_BLAS_IN_GEMM_IF_SIZE_GE`.
+        let a = moo![f64: 0..144].matrix::<Rust, 12, 12>();
+        let b = moo![f64: 0..144].matrix::<Blas, 12, 12>();
+        let c: [f64; 144] = a.matrix_mul(&a);
+        assert_eq!(c, b.matrix_mul(&b));
+    }
+
+    #[test]
+    fn matrix_mul_trans_a2() {
+        use slas::prelude::*;
+        use slas_backend::*;
+        let a = moo![f32: 1..=6].matrix::<Blas, 2, 3>();
+        let b = moo![f32: 1..=6].matrix::<Blas, 2, 3>();
+
+        let c = a.matrix_mul(&b.transpose());
+        assert_eq!(c, [14., 32., 32., 77.]);
+    }
 
     #[test]
     fn matrix_mul_trans_b2() {
@@ -492,7 +1215,7 @@ mod tensors {
             .moo_owned()
             .matrix::<slas_backend::Rust, 3, 3>();
 
-        let t = t.index_slice(1);
+        let t = t.index_slice::<3>(1);
 
         assert_eq!(t[[0]], 3.);
     }
@@ -504,7 +1227,7 @@ mod tensors {
         let t = moo![f32: 0..27]
             .moo_owned()
             .reshape(&[3, 3, 3], slas_backend::Rust);
-        let t = t.index_slice(1);
+        let t = t.index_slice::<9>(1);
 
         assert_eq!(t[[0, 0]], 9.);
     }
@@ -517,7 +1240,7 @@ mod tensors {
         let t = moo![f32: 0..27]
             .moo_owned()
             .reshape(&[3, 3, 3], slas_backend::Rust);
-        let mut t = t.index_slice(1);
+        let mut t = t.index_slice::<9>(1);
         t[[0, 0]] = 8.;
         assert_eq!(t[[0, 0]], 8.);
     }
@@ -529,7 +1252,7 @@ mod tensors {
         let mut t = moo![f32: 0..27]
             .moo_owned()
             .reshape(&[3, 3, 3], slas_backend::Rust);
-        let mut t = t.index_slice_mut(1);
+        let mut t = t.index_slice_mut::<9>(1);
         t[[0, 0]] = 8.;
         assert_eq!(t[[0, 0]], 8.);
     }
@@ -542,11 +1265,74 @@ mod tensors {
         let t = moo![f32: 0..27]
             .moo_owned()
             .reshape(&[3, 3, 3], slas_backend::Rust);
-        let t = t.index_slice(3);
+        let t = t.index_slice::<9>(3);
 
         assert_eq!(t[[2, 2]], 9.);
     }
 
+    #[test]
+    #[should_panic]
+    fn index_slice_wrong_volume() {
+        use slas::prelude::*;
+
+        let t = moo![f32: 0..24].reshape([2, 3, 4], slas_backend::Rust);
+        t.index_slice::<24>(0);
+    }
+
+    #[test]
+    fn index_slice_every_axis() {
+        use slas::prelude::*;
+
+        // Element [a, b, c] has the value a + 2b + 6c.
+        let t = moo![f32: 0..24].reshape([2, 3, 4], slas_backend::Rust);
+
+        // The last slice along the outermost axis ends exactly at the end of the tensor.
+        let s = t.index_slice::<6>(3);
+        assert_eq!(s[[0, 0]], 18.);
+        assert_eq!(s[[1, 2]], 23.);
+
+        let s = t.index_slice_axis::<12>(1, 0);
+        assert_eq!(s.shape, [3, 4]);
+        assert_eq!(s[[0, 0]], 1.);
+        assert_eq!(s[[2, 3]], 23.);
+
+        let s = t.index_slice_axis::<8>(2, 1);
+        assert_eq!(s.shape, [2, 4]);
+        assert_eq!(s[[0, 0]], 4.);
+        assert_eq!(s[[1, 1]], 11.);
+
+        let s = t.index_slice_axis::<6>(3, 2);
+        assert_eq!(s.shape, [2, 3]);
+        assert_eq!(s[[1, 0]], 19.);
+
+        for i in 0..4 {
+            let a = t.index_slice::<6>(i);
+            let b = t.index_slice_axis::<6>(i, 2);
+            for j in 0..2 {
+                for k in 0..3 {
+                    assert_eq!(a[[j, k]], b[[j, k]]);
+                    assert_eq!(b[[j, k]], t[[j, k, i]]);
+                }
+            }
+        }
+        for i in 0..3 {
+            let s = t.index_slice_axis::<8>(i, 1);
+            for j in 0..2 {
+                for k in 0..4 {
+                    assert_eq!(s[[j, k]], t[[j, i, k]]);
+                }
+            }
+        }
+        for i in 0..2 {
+            let s = t.index_slice_axis::<12>(i, 0);
+            for j in 0..3 {
+                for k in 0..4 {
+                    assert_eq!(s[[j, k]], t[[i, j, k]]);
+                }
+            }
+        }
+    }
+
     #[test]
     fn tensor_2d_to_matrix() {
         use slas::prelude::*;
@@ -579,6 +1365,1012 @@ mod tensors {
         assert_eq!(s.axis_len(0), 1);
         assert_eq!(s.axis_len(1), 2);
     }
+
+    #[test]
+    fn strides() {
+        use slas::prelude::*;
+        use slas::tensor::Shape;
+
+        let t = moo![f32: 0..24].reshape([2, 3, 4], slas_backend::Rust);
+        let strides = t.shape.strides();
+        assert_eq!(strides, [6, 2, 1]);
+        assert_eq!(
+            t[[1, 2, 3]],
+            (3 * strides[0] + 2 * strides[1] + strides[2]) as f32
+        );
+
+        assert_eq!(MatrixShape::<3, 5>.strides(), [5, 1]);
+        assert_eq!(MatrixShape::<3, 5>.strides(), [5, 3].strides());
+    }
+
+    #[test]
+    fn stack() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        let a = [1f32, 2., 3., 4., 5., 6.].matrix::<Rust, 2, 3>();
+        let b = [7f32, 8., 9., 10., 11., 12.].matrix::<Rust, 2, 3>();
+
+        let v = a.vstack(&b);
+        assert_eq!(v.rows(), 4);
+        assert_eq!(v.columns(), 3);
+        assert_eq!(
+            *v.vec_ref(),
+            [1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.]
+        );
+        assert_eq!(v[(2, 1)], 8.);
+
+        let h = a.hstack(&b);
+        assert_eq!(h.rows(), 2);
+        assert_eq!(h.columns(), 6);
+        assert_eq!(
+            *h.vec_ref(),
+            [1., 2., 3., 7., 8., 9., 4., 5., 6., 10., 11., 12.]
+        );
+        assert_eq!(h[(1, 4)], 11.);
+    }
+
+    #[test]
+    fn kron() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        let a = [1f32, 2., 3., 4.].matrix::<Rust, 2, 2>();
+        let b = [0f32, 5., 6., 7.].matrix::<Rust, 2, 2>();
+
+        assert_eq!(
+            a.kron(&b),
+            [
+                0., 5., 0., 10., //
+                6., 7., 12., 14., //
+                0., 15., 0., 20., //
+                18., 21., 24., 28.,
+            ]
+        );
+
+        let k = a.kron(&b).matrix::<Rust, 4, 4>();
+        assert_eq!(k[(3, 2)], 24.);
+    }
+
+    #[test]
+    fn matrix_vector_mul_backends() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        let data = [1f32, 2., 3., 4., 5., 6.];
+        let x = [1f32, 0., -1.];
+        let y = [1f32, 2.];
+
+        let blas: [f32; 2] = data.matrix::<Blas, 2, 3>().vector_mul(&x);
+        let rust: [f32; 2] = data.matrix::<Rust, 2, 3>().vector_mul(&x);
+        assert_eq!(blas, [-2., -2.]);
+        assert_eq!(rust, blas);
+
+        let blas: [f32; 3] = data.matrix::<Blas, 2, 3>().transpose().vector_mul(&y);
+        let rust: [f32; 3] = data.matrix::<Rust, 2, 3>().transpose().vector_mul(&y);
+        assert_eq!(blas, [9., 12., 15.]);
+        assert_eq!(rust, blas);
+    }
+
+    #[test]
+    fn symmetric_vector_mul() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        let a = [4f64, 1., -2., 1., 3., 0.5, -2., 0.5, 5.].matrix::<Blas, 3, 3>();
+        let x = [1f64, -1., 2.];
+        let y: [f64; 3] = a.vector_mul(&x);
+        assert_eq!(Blas.symmetric_vector_mul(&a, &x), y);
+    }
+
+    #[test]
+    fn col_major() {
+        use slas::prelude::*;
+        use slas::tensor::ColMajorMatrix;
+        use slas_backend::*;
+
+        let a: ColMajorMatrix<f32, _, Blas, 6, 3, 2> =
+            ColMajorMatrix::from_col_major([1., 2., 3., 4., 5., 6.]);
+        assert_eq!(a.rows(), 3);
+        assert_eq!(a.columns(), 2);
+        assert_eq!(a[(2, 0)], 3.);
+        assert_eq!(a[(0, 1)], 4.);
+
+        let r = a.to_row_major();
+        assert_eq!(*r.vec_ref(), [1., 4., 2., 5., 3., 6.]);
+
+        let b: ColMajorMatrix<f32, _, Blas, 4, 2, 2> =
+            ColMajorMatrix::from_col_major([1., 2., 3., 4.]);
+        let c: [f32; 6] = a.matrix_mul(&b);
+        assert_eq!(c, [9., 12., 15., 19., 26., 33.]);
+    }
+
+    #[test]
+    fn broadcast_add() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        let mut t = [0f32; 12].reshape([2, 3, 2], Rust);
+        let m = [1f32, 2., 3., 4., 5., 6.].reshape([2, 3], Rust);
+        t.broadcast_add(&m);
+        t.broadcast_add(&[10f32, 20.].reshape([2], Rust));
+        assert_eq!(
+            t.data.data,
+            [11., 22., 13., 24., 15., 26., 11., 22., 13., 24., 15., 26.]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn broadcast_add_wrong_shape() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        let mut t = [0f32; 6].reshape([2, 3], Rust);
+        t.broadcast_add(&[1f32, 2., 3.].reshape([3], Rust));
+    }
+
+    #[test]
+    fn triangular() {
+        use slas::prelude::*;
+        use slas_backend::*;
+
+        let a = [1f32, 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12.].matrix::<Rust, 4, 3>();
+        let u = a.triu().matrix::<Rust, 4, 3>();
+        let l = a.tril().matrix::<Rust, 4, 3>();
+
+        for n in 0..3 {
+            assert_eq!(u[(n, n)], a[(n, n)]);
+            assert_eq!(l[(n, n)], a[(n, n)]);
+        }
+        for r in 0..4 {
+            for c in 0..3 {
+                match c.cmp(&r) {
+                    std::cmp::Ordering::Greater => {
+                        assert_eq!(u[(r, c)], a[(r, c)]);
+                        assert_eq!(l[(r, c)], 0.);
+                    }
+                    std::cmp::Ordering::Less => {
+                        assert_eq!(u[(r, c)], 0.);
+                        assert_eq!(l[(r, c)], a[(r, c)]);
+                    }
+                    std::cmp::Ordering::Equal => {}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod dual {
+    use slas::prelude::*;
+
+    #[test]
+    fn dot_derivative() {
+        let f = |x: StaticCowVec<Dual<f32>, 3>| x.dot(&x);
+        let x = StaticCowVec::from([Dual::variable(1.), Dual::constant(2.), Dual::constant(3.)]);
+        assert_eq!(f(x), Dual::new(14., 2.));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let x = Dual::<f32>::variable(3.);
+        assert_eq!(x * x * x, Dual::new(27., 27.));
+        assert_eq!(Dual::constant(1.) / x, Dual::new(1. / 3., -1. / 9.));
+        assert_eq!(x.powi_(2), x * x);
+        assert_eq!(Dual::<f32>::variable(4.).sqrt_(), Dual::new(2., 0.25));
+    }
+}
+
+#[cfg(test)]
+mod unit_vec {
+    use slas::prelude::*;
+
+    #[test]
+    fn try_from_vec() {
+        let v = UnitVec::<f32, 2>::try_from_vec(&[3., 4.]).unwrap();
+        assert_eq!(**v, [0.6, 0.8]);
+        assert!(UnitVec::<f32, 2>::try_from_vec(&[0., 0.]).is_none());
+    }
+
+    #[test]
+    fn to_vec() {
+        let v = UnitVec::<f32, 2>::try_from_vec(&[3., 4.]).unwrap();
+        let mut w = v.to_vec();
+        w.mut_moo_ref()[0] = 1.;
+        assert_eq!(**w, [1., 0.8]);
+        assert_eq!(**v, [0.6, 0.8]);
+    }
+
+    #[test]
+    fn static_vec() {
+        let v = UnitVec::<f32, 2>::try_from_vec(&[3., 4.]).unwrap();
+        assert_eq!(cosine_similarity(&v, &[3., 4.]), 1.);
+        assert!((v.moo_ref().dot([1.; 2].moo_ref()) - 1.4).abs() < 1e-6);
+        assert_eq!(**v.as_ref(), [0.6, 0.8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn static_vec_mut() {
+        let mut v = UnitVec::<f32, 2>::try_from_vec(&[3., 4.]).unwrap();
+        v.mut_moo_ref()[0] = 1.;
+    }
+
+    #[test]
+    fn tagged_normalize() {
+        use slas::tags::NormalizedStaticCowVec;
+
+        let data = [3f32, 4.];
+        let v: NormalizedStaticCowVec<f32, 2> = StaticCowVec::from_ref(&data).normalize();
+        assert_eq!(**v, [0.6, 0.8]);
+        assert_eq!(data, [3., 4.]);
+
+        let mut w = v.untag();
+        w[0] = 1.;
+        assert_eq!(**w, [1., 0.8]);
+    }
+}
+
+#[cfg(test)]
+mod decompose {
+    use slas::decompose::*;
+    use slas::prelude::*;
+    use slas_backend::*;
+
+    #[test]
+    fn orthogonal_matrix() {
+        let a = moo![f32: 3, 1, 4, 2].matrix::<Rust, 2, 2>();
+        let q = OrthogonalMatrix::new(&a).unwrap();
+        assert!(crate::approx_eq(
+            q.as_matrix().vec_ref(),
+            &[0.6, -0.8, 0.8, 0.6]
+        ));
+    }
+
+    #[test]
+    fn gram_schmidt() {
+        let q = moo![f32: 2, -1, 0, 1, 3, 1, 0, 1, 4]
+            .matrix::<Blas, 3, 3>()
+            .gram_schmidt();
+        let qtq: [f32; 9] = q.transpose().matrix_mul(&q);
+        assert!(crate::approx_eq(
+            &qtq,
+            &[1., 0., 0., 0., 1., 0., 0., 0., 1.]
+        ));
+
+        let q = moo![f32: 1, 0, 2, 1, 0, 1, 1, 1, 3, 0, 1, 2]
+            .matrix::<Blas, 4, 3>()
+            .gram_schmidt();
+        let qtq: [f32; 9] = q.transpose().matrix_mul(&q);
+        assert!(crate::approx_eq(
+            &qtq,
+            &[1., 0., 0., 0., 1., 0., 0., 0., 1.]
+        ));
+    }
+
+    #[test]
+    fn givens() {
+        let mut a = moo![f32: 3, 1, 2, 0, 5, 1, 4, 2, 6].matrix::<Rust, 3, 3>();
+        let (c, s) = givens_rotation(a[(0, 0)], a[(2, 0)]);
+        apply_givens_left(c, s, 0, 2, &mut a);
+
+        assert!(crate::approx_eq(
+            a.vec_ref(),
+            &[5., 2.2, 6., 0., 5., 1., 0., 0.4, 2.]
+        ));
+
+        let mut a = moo![f32: 3, 0, 4, 1, 5, 2, 2, 1, 6].matrix::<Rust, 3, 3>();
+        let (c, s) = givens_rotation(a[(0, 0)], a[(0, 2)]);
+        apply_givens_right(c, s, 0, 2, &mut a);
+
+        assert!(crate::approx_eq(
+            a.vec_ref(),
+            &[5., 0., 0., 2.2, 5., 0.4, 6., 1., 2.]
+        ));
+    }
+
+    #[test]
+    fn lu_decomposition() {
+        let a = moo![f32: 2, 1, 1, 4, -6, 0, -2, 7, 2].matrix::<Blas, 3, 3>();
+
+        #[cfg(feature = "lapack")]
+        let decompositions = [lu(&a), lu_blas(&a)];
+        #[cfg(not(feature = "lapack"))]
+        let decompositions = [lu(&a)];
+
+        for (l, u, p) in decompositions {
+            let lu: [f32; 9] = l
+                .matrix::<Blas, 3, 3>()
+                .matrix_mul(&u.matrix::<Blas, 3, 3>());
+            for r in 0..3 {
+                for c in 0..3 {
+                    assert!((lu[r * 3 + c] - a[(p[r], c)]).abs() < 1e-5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn permutation() {
+        assert!(Perm::new([0, 0, 1]).is_none());
+        assert!(Perm::new([0, 3, 1]).is_none());
+
+        let p = Perm::new([1, 2, 0, 3]).unwrap();
+        let v = [10., 20., 30., 40.];
+        assert_eq!(p.apply(&v), [20., 30., 10., 40.]);
+        assert_eq!(p.apply_inv(&p.apply(&v)), v);
+        assert!(!p.is_odd());
+        assert!(Perm::new([1, 0, 2, 3]).unwrap().is_odd());
+        assert!(!Perm::<4>::identity().is_odd());
+
+        let mut a = [1f32, 2., 3., 4., 5., 6.].matrix::<Rust, 3, 2>();
+        Perm::new([2, 0, 1]).unwrap().apply_left(&mut a);
+        assert_eq!(*a.vec_ref(), [5., 6., 1., 2., 3., 4.]);
+
+        // The parity of the permutation from LU decomposition gives the sign of the determinant.
+        let a = moo![f32: 2, 1, 1, 4, -6, 0, -2, 7, 2].matrix::<Rust, 3, 3>();
+        let (_, u, p) = lu(&a);
+        let p = Perm::new(p).unwrap();
+        let sign = if p.is_odd() { -1. } else { 1. };
+        assert!((sign * u[0] * u[4] * u[8] - a.det()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn solve() {
+        let a = moo![f32: 2, 1, 1, 4, -6, 0, -2, 7, 2].matrix::<Blas, 3, 3>();
+        let b = [5., -2., 9.];
+
+        let x = a.solve(&b);
+        assert!(crate::approx_eq(&a.vector_mul(&x), &b));
+
+        let a = moo![f32: 2, 1, 1, 4, -6, 0, -2, 7, 2].matrix::<Rust, 3, 3>();
+        assert!(crate::approx_eq(&a.solve(&b), &x));
+    }
+
+    #[test]
+    fn inverse() {
+        let a = moo![f32: 2, 1, 1, 4, -6, 0, -2, 7, 2].matrix::<Rust, 3, 3>();
+        let i = [1., 0., 0., 0., 1., 0., 0., 0., 1.];
+
+        let a_inv = a.inv().unwrap();
+        let prod: [f32; 9] = a.matrix_mul(&a_inv.matrix::<Rust, 3, 3>());
+        assert!(crate::approx_eq(&prod, &i));
+
+        #[cfg(feature = "lapack")]
+        {
+            let b = moo![f32: 2, 1, 1, 4, -6, 0, -2, 7, 2].matrix::<Blas, 3, 3>();
+            assert!(crate::approx_eq(&b.inv().unwrap(), &a_inv));
+        }
+
+        let singular = moo![f32: 1, 2, 3, 4, 5, 6, 7, 8, 9].matrix::<Rust, 3, 3>();
+        assert_eq!(singular.inv(), None);
+    }
+
+    #[test]
+    fn determinant() {
+        assert_eq!(moo![f32: -3].matrix::<Rust, 1, 1>().det(), -3.);
+        assert_eq!(moo![f32: 3, 8, 4, 6].matrix::<Rust, 2, 2>().det(), -14.);
+        assert!(
+            (moo![f32: 6, 1, 1, 4, -2, 5, 2, 8, 7]
+                .matrix::<Rust, 3, 3>()
+                .det()
+                + 306.)
+                .abs()
+                < 1e-4
+        );
+
+        assert_eq!(moo![f32: 1, 2, 2, 4].matrix::<Rust, 2, 2>().det(), 0.);
+        assert_eq!(
+            moo![f32: 1, 2, 3, 2, 4, 6, 1, 0, 1]
+                .matrix::<Rust, 3, 3>()
+                .det(),
+            0.
+        );
+    }
+
+    #[test]
+    fn qr_decomposition() {
+        let a = moo![f32: 12, -51, 4, 6, 167, -68, -4, 24, -41].matrix::<Blas, 3, 3>();
+        let b = moo![f32: 1, 2, 3, 4, 5, 6].matrix::<Blas, 3, 2>();
+
+        #[cfg(feature = "lapack")]
+        let (square, tall) = ([a.qr(), a.qr_blas()], [b.qr(), b.qr_blas()]);
+        #[cfg(not(feature = "lapack"))]
+        let (square, tall) = ([a.qr()], [b.qr()]);
+
+        for (q, r) in square {
+            let q = q.matrix::<Blas, 3, 3>();
+            let qr: [f32; 9] = q.matrix_mul(&r.matrix::<Blas, 3, 3>());
+            let qtq: [f32; 9] = q.transpose().matrix_mul(&q);
+
+            assert!(qr
+                .iter()
+                .zip(a.vec_ref().iter())
+                .all(|(a, b)| (a - b).abs() < 1e-3));
+            assert!(crate::approx_eq(
+                &qtq,
+                &[1., 0., 0., 0., 1., 0., 0., 0., 1.]
+            ));
+            assert_eq!([r[3], r[6], r[7]], [0.; 3]);
+        }
+
+        for (q, r) in tall {
+            let q = q.matrix::<Blas, 3, 3>();
+            let qr: [f32; 6] = q.matrix_mul(&r.matrix::<Blas, 3, 2>());
+            assert!(crate::approx_eq(&qr, b.vec_ref()));
+            assert_eq!([r[2], r[4], r[5]], [0.; 3]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "lapack")]
+    fn svd_round_trip() {
+        fn check<const M: usize, const K: usize, const LEN: usize>(a: [f64; LEN])
+        where
+            [(); M * M]:,
+            [(); min_dim(M, K)]:,
+            [(); K * K]:,
+        {
+            let a = a.matrix::<Blas, M, K>();
+            let SvdResult { u, s, vt } = svd(&a);
+
+            assert!(s.windows(2).all(|w| w[0] >= w[1]));
+            for r in 0..M {
+                for c in 0..K {
+                    let usv: f64 = (0..min_dim(M, K))
+                        .map(|n| u[r * M + n] * s[n] * vt[n * K + c])
+                        .sum();
+                    assert!((usv - a[(r, c)]).abs() < 1e-10);
+                }
+            }
+        }
+
+        check::<2, 2, 4>([3., 0., 0., -2.]);
+        check::<3, 2, 6>([1., 2., 3., 4., 5., 6.]);
+        check::<2, 3, 6>([1., -2., 0.5, 4., 5., -6.]);
+    }
+
+    #[test]
+    #[cfg(feature = "lapack")]
+    fn pinv() {
+        fn check<const M: usize, const K: usize>(a: [f64; M * K])
+        where
+            [(); M * M]:,
+            [(); min_dim(M, K)]:,
+            [(); K * K]:,
+            [(); K * M]:,
+        {
+            let a = a.matrix::<Blas, M, K>();
+            let p = a.pinv();
+
+            for r in 0..M {
+                for c in 0..K {
+                    let apa: f64 = (0..K)
+                        .flat_map(|i| (0..M).map(move |j| (i, j)))
+                        .map(|(i, j)| a[(r, i)] * p[(i, j)] * a[(j, c)])
+                        .sum();
+                    assert!((apa - a[(r, c)]).abs() < 1e-10);
+                }
+            }
+        }
+
+        check::<3, 2>([1., 2., 3., 4., 5., 6.]);
+        check::<2, 3>([1., -2., 0.5, 4., 5., -6.]);
+        // Rank deficient
+        check::<3, 3>([1., 2., 3., 2., 4., 6., 1., 0., 1.]);
+    }
+
+    #[test]
+    fn linearly_dependent() {
+        let a = moo![f32: 1, 2, 2, 4].matrix::<Rust, 2, 2>();
+        assert_eq!(
+            OrthogonalMatrix::new(&a).err(),
+            Some(OrthogonalizationError::LinearlyDependent(1))
+        );
+
+        let a = moo![f32: 1, 2, 3, 4, 5, 6].matrix::<Rust, 2, 3>();
+        assert_eq!(
+            OrthogonalMatrix::new(&a).err(),
+            Some(OrthogonalizationError::TooManyColumns)
+        );
+    }
+}
+
+#[cfg(test)]
+mod eigen {
+    use slas::eigen::*;
+    use slas::prelude::*;
+
+    #[test]
+    fn dominant_eigenpair() {
+        let a = moo![f32: 2, 0, 0, 0, 3, 4, 0, 4, 9].matrix::<slas_backend::Blas, 3, 3>();
+        let (value, vector) = power_iteration(&a, 1000, 1e-7).unwrap();
+
+        assert!((value - 11.).abs() < 1e-3);
+
+        let expected = [0., 1. / 5f32.sqrt(), 2. / 5f32.sqrt()];
+        let sign = vector[2].signum();
+        assert!(crate::approx_eq(&vector.map(|n| n * sign), &expected));
+
+        assert_eq!(
+            power_iteration(&a, 1, 1e-7),
+            Err(PowerIterError::NotConverged)
+        );
+    }
+
+    #[test]
+    fn lanczos_eigenpairs() {
+        fn check<const N: usize, const K: usize>(a: [f64; N * N], expected: [f64; K])
+        where
+            [(); K * K]:,
+            [(); N * K]:,
+        {
+            let a = a.matrix::<slas_backend::Rust, N, N>();
+            let (values, vectors) = lanczos::<f64, _, _, N, { N * N }, K>(&a);
+
+            for k in 0..K {
+                assert!((values[k] - expected[k]).abs() < 1e-6);
+
+                let mut v = [0.; N];
+                for n in 0..N {
+                    v[n] = vectors[n * K + k];
+                }
+                let av: [f64; N] = a.vector_mul(&v);
+                assert!((0..N).all(|n| (av[n] - values[k] * v[n]).abs() < 1e-4));
+            }
+        }
+
+        check::<3, 3>([2., 0., 0., 0., 3., 4., 0., 4., 9.], [11., 2., 1.]);
+
+        // Eigenvalues with the same magnitude and opposite signs, which unshifted QR iteration can't separate.
+        check::<2, 2>([0., 1., 1., 0.], [1., -1.]);
+        check::<3, 3>([0., 2., 0., 2., 0., 0., 0., 0., 1.], [2., 1., -2.]);
+
+        // The Krylov subspace becomes invariant after 3 steps, as a only has 3 distinct eigenvalues.
+        let mut a = [0.; 36];
+        for (n, d) in [100., 50., 1., 1., 1., 1.].iter().enumerate() {
+            a[n * 6 + n] = *d;
+        }
+        check::<6, 4>(a, [100., 50., 1., 1.]);
+    }
+
+    #[test]
+    fn deflated_spectrum() {
+        let a = moo![f32: 2, 0, 0, 0, 3, 4, 0, 4, 9].matrix::<slas_backend::Blas, 3, 3>();
+
+        let mut found = vec![];
+        let mut values = vec![];
+        for _ in 0..3 {
+            let (value, vector) = deflated_power_iteration(&a, &found, 1000, 1e-7).unwrap();
+            values.push(value);
+            found.push(vector);
+        }
+        assert!(values
+            .iter()
+            .zip([11., 2., 1.].iter())
+            .all(|(a, b)| (a - b).abs() < 1e-3));
+
+        let basis = [[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+        assert_eq!(
+            deflated_power_iteration(&a, &basis, 1000, 1e-7),
+            Err(PowerIterError::Deflated)
+        );
+    }
+}
+
+#[cfg(test)]
+mod solvers {
+    use slas::prelude::*;
+    use slas::solvers::*;
+
+    #[test]
+    fn conjugate_gradient() {
+        let a = moo![f64: 4, 1, 0, 1, 3, 1, 0, 1, 2].matrix::<slas_backend::Rust, 3, 3>();
+        let b = [1., 2., 3.];
+
+        let (x, iters) = cg_solve::<f64, _, _, 3, 9, 100>(&a, &b, 1e-12);
+        assert!(iters <= 4);
+        let ax: [f64; 3] = a.vector_mul(&x);
+        assert!(ax.iter().zip(b.iter()).all(|(a, b)| (a - b).abs() < 1e-10));
+
+        let diag = [4., 3., 2.];
+        let (y, _) = pcg_solve::<f64, _, _, 3, 9, 100>(&a, &b, 1e-12, |r| {
+            [r[0] / diag[0], r[1] / diag[1], r[2] / diag[2]]
+        });
+        assert!(x.iter().zip(y.iter()).all(|(a, b)| (a - b).abs() < 1e-10));
+
+        // Not enough iterations to converge.
+        assert_eq!(cg_solve::<f64, _, _, 3, 9, 1>(&a, &b, 1e-12).1, 1);
+    }
+}
+
+#[cfg(test)]
+mod ml {
+    use slas::prelude::*;
+
+    #[test]
+    fn softmax() {
+        let s = moo![f32: -3, 0.5, 2, 10, 1000].softmax();
+        assert!((s.iter().sum::<f32>() - 1.).abs() < 1e-5);
+        assert!(s.iter().all(|n| (0. ..=1.).contains(n)));
+        assert!(s[4] > s[3] && s[3] >= s[2]);
+    }
+
+    #[test]
+    fn log_sum_exp() {
+        // exp(1000) overflows f32, so the naive implementation would return inf.
+        let v = moo![f32: 1000, 999, 998];
+        let expected = 1000. + (1. + (-1f32).exp() + (-2f32).exp()).ln();
+        assert!((v.log_sum_exp() - expected).abs() < 1e-3);
+
+        let v = moo![f32: 0, 0, 0, 0];
+        assert!((v.log_sum_exp() - 4f32.ln()).abs() < 1e-5);
+    }
+
+    #[test]
+    fn batch_norm() {
+        let mut x = moo![f32: 0..8];
+        batch_norm_inplace(&mut x, &[1.; 8], &[0.; 8], 1e-5);
+        assert!(x.mean().abs() < 1e-5);
+        assert!((x.variance() - 1.).abs() < 1e-4);
+
+        let mut y = moo![f32: 0..8];
+        batch_norm_inplace(&mut y, &[2.; 8], &[3.; 8], 1e-5);
+        for n in 0..8 {
+            assert!((y[n] - (x[n] * 2. + 3.)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn classification_losses() {
+        let p = [0.1f64, 0.6, 0.3, 0.];
+        let q = [0.2f64, 0.2, 0.5, 0.1];
+
+        let kl: f64 = (0..3).map(|n| p[n] * (p[n] / q[n]).ln()).sum();
+        assert!((kl_divergence(&p, &q) - kl).abs() < 1e-12);
+        assert!(kl_divergence(&p, &q) > 0.);
+
+        let h: f64 = -(0..3).map(|n| p[n] * q[n].ln()).sum::<f64>();
+        assert!((cross_entropy(&p, &q) - h).abs() < 1e-12);
+
+        // Cross-entropy is the entropy of p plus the KL divergence.
+        let entropy = cross_entropy(&p, &p);
+        assert!((cross_entropy(&p, &q) - entropy - kl_divergence(&p, &q)).abs() < 1e-12);
+
+        let y = [1f64, 0., 1.];
+        let y_pred = [0.9f64, 0.2, 0.6];
+        let bce = -(0.9f64.ln() + 0.8f64.ln() + 0.6f64.ln()) / 3.;
+        assert!((binary_cross_entropy(&y, &y_pred) - bce).abs() < 1e-12);
+    }
+}
+
+#[cfg(test)]
+mod stats {
+    use slas::prelude::*;
+
+    #[test]
+    fn mean_variance_std_dev() {
+        let v = moo![f64: 2, 4, 4, 4, 5, 5, 7, 9];
+        assert_eq!(v.mean(), 5.);
+        assert_eq!(v.variance(), 4.);
+        assert_eq!(v.std_dev(), 2.);
+
+        let v = moo![f32: 3; 16];
+        assert_eq!(v.mean(), 3.);
+        assert_eq!(v.variance(), 0.);
+
+        let v = moo![f32: 1..5];
+        assert!((v.mean() - 2.5).abs() < 1e-6);
+        assert!((v.variance() - 1.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn welford_matches_batch_statistics() {
+        let samples = [[1.5, -2.], [3., 0.25], [-0.5, 8.], [2., 2.], [10., -1.]];
+
+        let mut acc = WelfordAccumulator::<f64, 2>::default();
+        for sample in samples.iter() {
+            acc.update(sample);
+        }
+        assert_eq!(acc.count(), 5);
+
+        for feature in 0..2 {
+            let column = moo![|n| -> f64 { samples[n][feature] }; 5];
+            assert!((acc.mean()[feature] - column.mean()).abs() < 1e-12);
+            assert!((acc.variance()[feature] - column.variance()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn covariance_of_standard_normal() {
+        const N: usize = 4000;
+
+        // Xorshift and Box-Muller, so the sample is deterministic without depending on rand.
+        let mut state = 0x2545f491u32;
+        let mut uniform = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state as f64 + 1.) / (u32::MAX as f64 + 2.)
+        };
+
+        let mut sample = [0f64; N * 2];
+        for n in 0..N {
+            let (u1, u2) = (uniform(), uniform());
+            let r = (-2. * u1.ln()).sqrt();
+            let theta = 2. * std::f64::consts::PI * u2;
+            sample[n * 2] = r * theta.cos();
+            sample[n * 2 + 1] = r * theta.sin();
+        }
+
+        let cov = sample.matrix::<slas_backend::Rust, N, 2>().covariance();
+        let expected = [1., 0., 0., 1.];
+        for (a, b) in cov.vec_ref().iter().zip(expected.iter()) {
+            assert!((a - b).abs() < 0.1, "{:?}", cov.vec_ref());
+        }
+    }
+}
+
+#[cfg(test)]
+mod distance {
+    use slas::prelude::*;
+
+    #[test]
+    fn cosine_similarity_range() {
+        let a = moo![f32: 1, 2, 3];
+        assert!((cosine_similarity(&a, &a) - 1.).abs() < 1e-6);
+        assert!((cosine_similarity(&a, &[-2., -4., -6.]) + 1.).abs() < 1e-6);
+        assert_eq!(cosine_similarity(&a, &[0.; 3]), 0.);
+
+        let s = cosine_similarity(&a, &[3., -1., 0.5]);
+        assert!((-1. ..=1.).contains(&s));
+    }
+
+    #[test]
+    fn euclidean_distance_non_negative() {
+        let a = [1f64, -2., 3.];
+        let b = [4f64, 2., 3.];
+        assert_eq!(euclidean_distance(&a, &b), 5.);
+        assert_eq!(euclidean_distance(&b, &a), 5.);
+        assert_eq!(euclidean_distance(&a, &a), 0.);
+    }
+}
+
+#[cfg(test)]
+mod einsum {
+    use slas::prelude::*;
+    use slas_backend::*;
+
+    #[test]
+    fn matrix_mul() {
+        let a = [1f32, 2., 3., 4., 5., 6.].matrix::<Blas, 2, 3>();
+        let b = [1f32, 0., 2., 1., 0., 3.].matrix::<Blas, 3, 2>();
+        let c: [f32; 4] = slas::einsum!("ij,jk->ik", &a, &b);
+        assert_eq!(c, [5., 11., 14., 23.]);
+
+        // Same contraction with different index names uses the generic implementation.
+        let d: [f32; 4] = slas::einsum!("ab,bc->ac", &a, &b);
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn contractions() {
+        let a = [1f32, 2., 3., 4., 5., 6.].matrix::<Rust, 2, 3>();
+        let v = [1f32, 1., 2.].reshape([3], Rust);
+
+        let av: [f32; 2] = slas::einsum!("ij,j->i", &a, &v);
+        assert_eq!(av, [9., 21.]);
+
+        let dot: [f32; 1] = slas::einsum!("i,i->", &v, &v);
+        assert_eq!(dot, [6.]);
+
+        let outer: [f32; 9] = slas::einsum!("i,j->ij", &v, &v);
+        assert_eq!(outer, [1., 1., 2., 1., 1., 2., 2., 2., 4.]);
+
+        let sum: [f32; 3] = slas::einsum!("ij->j", &a);
+        assert_eq!(sum, [5., 7., 9.]);
+    }
+
+    #[test]
+    fn transposed() {
+        let a = [1f32, 2., 3., 4., 5., 6.].matrix::<Rust, 2, 3>();
+        let b = [1f32, 0., 2., 1., 0., 3.].matrix::<Rust, 2, 3>();
+
+        // a * b^T, with b lazily transposed.
+        let c: [f32; 4] = slas::einsum!("ij,jk->ik", &a, &b.transpose());
+        let d: [f32; 4] = slas::einsum!("ab,bc->ac", &a, &b.transpose());
+        assert_eq!(c, [7., 10., 16., 22.]);
+        assert_eq!(c, d);
+
+        let at: [f32; 6] = slas::einsum!("ij->ij", &a.transpose());
+        assert_eq!(at, [1., 4., 2., 5., 3., 6.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn inconsistent_lengths() {
+        let a = [0f32; 6].matrix::<Rust, 2, 3>();
+        let _: [f32; 4] = slas::einsum!("ij,jk->ik", &a, &a);
+    }
+}
+
+#[cfg(test)]
+mod poly {
+    use slas::poly::*;
+
+    #[test]
+    fn degree_4() {
+        // (x - 1)(x - 2)(x + 1)(x + 3) = x^4 + x^3 - 7x^2 - x + 6
+        let p = [6f32, -1., -7., 1., 1.];
+        for root in [1., 2., -1., -3.] {
+            assert_eq!(polynomial_eval(&p, root), 0.);
+        }
+        assert_eq!(polynomial_eval(&p, 0.), 6.);
+        assert_eq!(polynomial_eval(&p, 3.), 48.);
+
+        let xs = [1f32, 2., -1., -3., 0., 3.];
+        assert_eq!(polynomial_eval_batch(&p, &xs), [0., 0., 0., 0., 6., 48.]);
+    }
+}
+
+#[cfg(test)]
+mod interpolate {
+    use slas::prelude::*;
+
+    #[test]
+    fn lerp_endpoints() {
+        let a = moo![f32: 1, -2, 3];
+        let b = [5f32, 2., -1.];
+
+        assert_eq!(*lerp(&a, &b, 0.), **a);
+        assert_eq!(*lerp(&a, &b, 1.), b);
+        assert_eq!(*lerp(&a, &b, 0.5), [3., 0., 1.]);
+
+        let mut c = [1f32, -2., 3.];
+        lerp_in_place(&mut c, &b, 0.25);
+        assert_eq!(c, [2., -1., 2.]);
+    }
+
+    #[test]
+    fn slerp_unit_vectors() {
+        let a = [1f32, 0., 0.];
+        let b = [0f32, 0., 1.];
+
+        assert!(crate::approx_eq(&slerp(&a, &b, 0.), &a));
+        assert!(crate::approx_eq(&slerp(&a, &b, 1.), &b));
+
+        let s = slerp(&a, &b, 1. / 3.);
+        assert!((s.moo_ref().dot(s.moo_ref()) - 1.).abs() < 1e-5);
+        assert!(crate::approx_eq(
+            &s,
+            &[(std::f32::consts::PI / 6.).cos(), 0., 0.5]
+        ));
+
+        assert_eq!(*slerp(&a, &a, 0.5), a);
+    }
+}
+
+#[cfg(test)]
+mod transform {
+    use slas::prelude::*;
+    use slas_backend::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    type Mat3 = Matrix<f32, [f32; 9], Blas, 9, false, MatrixShape<3, 3>>;
+
+    #[test]
+    fn rotation_3d() {
+        let v = [1f32, 2., 3.];
+
+        let x: [f32; 3] = Mat3::rotation_3d_x(FRAC_PI_2).vector_mul(&v);
+        assert!(crate::approx_eq(&x, &[1., -3., 2.]));
+
+        let y: [f32; 3] = Mat3::rotation_3d_y(FRAC_PI_2).vector_mul(&v);
+        assert!(crate::approx_eq(&y, &[3., 2., -1.]));
+
+        let z: [f32; 3] = Mat3::rotation_3d_z(FRAC_PI_2).vector_mul(&v);
+        assert!(crate::approx_eq(&z, &[-2., 1., 3.]));
+    }
+
+    #[test]
+    fn affine() {
+        use slas::decompose::OrthogonalMatrix;
+        use slas::transform::Affine;
+
+        let r = OrthogonalMatrix::new(&[0f32, -1., 1., 0.].matrix::<Rust, 2, 2>()).unwrap();
+        let rotate = Affine::rotate(&r);
+        let translate = Affine::translate(&[1., 0.]);
+        let scale = Affine::scale(&[2., 2.]);
+
+        assert_eq!(rotate.apply_point(&[1., 0.]), [0., 1.]);
+        assert_eq!(translate.apply_point(&[1., 0.]), [2., 0.]);
+        assert_eq!(scale.apply_point(&[1., 3.]), [2., 6.]);
+
+        // Scale, then rotate, then translate.
+        let a = translate.compose(&rotate).compose(&scale);
+        assert_eq!(a.apply_point(&[1., 0.]), [1., 2.]);
+        assert_eq!(a.compose(&Affine::identity()), a);
+
+        let m = a.matrix::<Rust>();
+        assert_eq!(m[(0, 2)], 1.);
+        assert_eq!(m[(2, 2)], 1.);
+    }
+
+    #[test]
+    fn rodrigues() {
+        let axis = UnitVec::try_from_vec(&[1f32, 1., 1.]).unwrap();
+        let r = Mat3::rotation_3d_axis_angle(&axis, 2. * std::f32::consts::PI / 3.);
+
+        // A third of a turn around the diagonal cycles the axes.
+        let v: [f32; 3] = r.vector_mul(&[1., 2., 3.]);
+        assert!(crate::approx_eq(&v, &[3., 1., 2.]));
+
+        // Rotation matricies are orthogonal.
+        let rt = r.vec_ref().matrix::<Blas, 3, 3>().transpose();
+        let i: [f32; 9] = r.matrix_mul(&rt);
+        assert!(crate::approx_eq(&i, &[1., 0., 0., 0., 1., 0., 0., 0., 1.]));
+    }
+}
+
+#[cfg(test)]
+mod signal {
+    use slas::prelude::*;
+    use slas::signal::*;
+
+    #[test]
+    fn fwht_basis_vector() {
+        let mut e0 = [0f32; 8];
+        e0[0] = 1.;
+        assert_eq!(fwht(&e0), [1.; 8]);
+
+        let mut e3 = [0f32; 8];
+        e3[3] = 1.;
+        let h = fwht(&e3);
+        assert!(h.iter().all(|n| n.abs() == 1.));
+        assert_eq!(h.iter().sum::<f32>(), 0.);
+    }
+
+    #[test]
+    fn windows() {
+        let v = moo![f32: 0..6];
+        let max = v.windows_apply(|w: &[f32; 3]| w.iter().cloned().fold(f32::MIN, f32::max));
+        assert_eq!(max, [2., 3., 4., 5.]);
+
+        let firsts: [f32; 1] = v.windows_apply(|w: &[f32; 6]| w[0]);
+        assert_eq!(firsts, [0.]);
+
+        assert_eq!(v.correlate(&[1., 0., -1.]), [-2.; 4]);
+        assert_eq!(v.correlate(&[0.5; 2]), [0.5, 1.5, 2.5, 3.5, 4.5]);
+    }
+
+    #[test]
+    fn convolve() {
+        let v = moo![f32: 0, 0, 1, 0, 0, 2];
+        let kernel = [1f32, 2., 3.];
+
+        // The impulse response is the kernel itself.
+        assert_eq!(v.convolve_full(&kernel), [0., 0., 1., 2., 3., 2., 4., 6.]);
+        assert_eq!(v.convolve_same(&kernel), [0., 1., 2., 3., 2., 4.]);
+        assert_eq!(v.convolve_valid(&kernel), [1., 2., 3., 2.]);
+
+        // Moving average.
+        let step = moo![f32: 0, 0, 3, 3, 3, 3];
+        assert!(crate::approx_eq(
+            &step.convolve_valid(&[1. / 3.; 3]),
+            &[1., 2., 3., 3.]
+        ));
+    }
+
+    #[test]
+    fn fwht_inverse() {
+        let v = moo![f32: 0..16];
+        let mut w = fwht(&v);
+        fwht_inplace(&mut w);
+        for n in 0..16 {
+            assert_eq!(w[n], v[n] * 16.);
+        }
+    }
 }
 
 #[cfg(all(test, feature = "versus"))]